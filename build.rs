@@ -0,0 +1,55 @@
+//! Generates `$OUT_DIR/solutions.rs`, which compiles every solution in `src/bin` as a module of the
+//! main binary and collects their registry entries. This lets `all` and `time` run solutions in-process.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut puzzles: Vec<String> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let file_name = entry.file_name().into_string().ok()?;
+                    let puzzle = file_name.strip_suffix(".rs")?;
+                    // solutions are named `<year>-<day>`, e.g. `2025-01`.
+                    let (year, day) = puzzle.split_once('-')?;
+                    let is_digits =
+                        |x: &str, len| x.len() == len && x.chars().all(|c| c.is_ascii_digit());
+                    (is_digits(year, 4) && is_digits(day, 2)).then(|| puzzle.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    puzzles.sort_unstable();
+
+    let mut module = String::from("// @generated by build.rs from the solutions in `src/bin`.\n\n");
+
+    for puzzle in &puzzles {
+        let path = bin_dir.join(format!("{puzzle}.rs"));
+        println!("cargo:rerun-if-changed={}", path.display());
+        module.push_str(&format!(
+            "#[allow(warnings, clippy::all, clippy::pedantic)]\n#[path = {:?}]\nmod {};\n\n",
+            path.display().to_string(),
+            module_name(puzzle)
+        ));
+    }
+
+    module.push_str("pub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for puzzle in &puzzles {
+        module.push_str(&format!("    {}::SOLUTION,\n", module_name(puzzle)));
+    }
+    module.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, module).unwrap();
+}
+
+/// Module name of a solution, e.g. `day_2025_01`.
+fn module_name(puzzle: &str) -> String {
+    format!("day_{}", puzzle.replace('-', "_"))
+}
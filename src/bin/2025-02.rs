@@ -1,7 +1,22 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2025, 2);
 
+use std::ops::RangeInclusive;
+
+use advent_of_code::parse::{ParseError, Span};
 use rayon::prelude::*;
 
+/// Parse the comma-separated inclusive ranges, e.g. `11-22,95-115`. Ranges are kept as listed, so an
+/// ID in two overlapping ranges counts twice.
+fn parse_ranges(input: &str) -> Result<Vec<RangeInclusive<usize>>, ParseError> {
+    Span::new(input.trim_end())
+        .split(",")
+        .map(|range| {
+            let (start, end) = range.split_once("-")?;
+            Ok(start.parse()?..=end.parse()?)
+        })
+        .collect()
+}
+
 // Return true if the first half of the digits in the number are the same as the second half
 fn has_repeat(num: usize) -> bool {
     let num_str = num.to_string();
@@ -38,32 +53,22 @@ fn has_repeats(num: usize) -> bool {
     false
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let ranges = input.split(',').collect::<Vec<&str>>();
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let ranges = parse_ranges(input)?;
     let result = ranges
         .par_iter()
-        .map(|s| {
-            let r = s.split('-').collect::<Vec<&str>>();
-            let start: usize = r[0].parse().unwrap();
-            let end: usize = r[1].parse().unwrap();
-            (start..=end).filter(|&n| has_repeat(n)).sum::<usize>()
-        })
+        .map(|range| range.clone().filter(|&n| has_repeat(n)).sum::<usize>())
         .sum::<usize>();
-    Some(result as u64)
+    Ok(result as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let ranges = input.split(',').collect::<Vec<&str>>();
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let ranges = parse_ranges(input)?;
     let result = ranges
         .par_iter()
-        .map(|s| {
-            let r = s.split('-').collect::<Vec<&str>>();
-            let start: usize = r[0].parse().unwrap();
-            let end: usize = r[1].parse().unwrap();
-            (start..=end).filter(|&n| has_repeats(n)).sum::<usize>()
-        })
+        .map(|range| range.clone().filter(|&n| has_repeats(n)).sum::<usize>())
         .sum::<usize>();
-    Some(result as u64)
+    Ok(result as u64)
 }
 
 #[cfg(test)]
@@ -95,13 +100,26 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1227775554));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 1227775554);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4174379265));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 4174379265);
+    }
+
+    #[test]
+    fn test_overlapping_ranges() {
+        // 11 and 22 are in both ranges and count twice, 33 once
+        let result = part_one("11-22,11-33");
+        assert_eq!(result.unwrap(), 99);
+    }
+
+    #[test]
+    fn test_malformed_range() {
+        let error = part_one("11-22,95+115").unwrap_err();
+        assert_eq!(error.location(), (1, 7));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(2025, 3);
 
 // Return the largest digit and its index in [0..numbers.len()-n]
 fn largest_digit_n_from_end(numbers: &[u64], n: usize) -> (u64, u64) {
@@ -83,13 +83,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(357));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(3121910778619));
     }
 }
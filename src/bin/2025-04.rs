@@ -0,0 +1,67 @@
+use advent_of_code::{
+    grid::{Grid, Pos},
+    parse::ParseError,
+};
+
+advent_of_code::solution!(2025, 4, parse);
+
+// Return the count of occupied cells around a cell of the grid.
+// Taking account that edges/corners have less than 8 adjacent elements.
+fn get_num_adjacent_elements(grid: &Grid<bool>, pos: Pos) -> usize {
+    grid.neighbours8(pos).filter(|&next| grid[next]).count()
+}
+
+// Remove all accessible elements from the grid and return how many were removed.
+// An accessible element is one that has fewer than 4 adjacent elements
+fn remove_accessible_elements(grid: &mut Grid<bool>) -> usize {
+    let accessible: Vec<Pos> = grid
+        .positions()
+        .filter(|&pos| grid[pos] && get_num_adjacent_elements(grid, pos) < 4)
+        .collect();
+    for &pos in &accessible {
+        grid[pos] = false;
+    }
+    accessible.len()
+}
+
+fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input, |ch| ch == '@')
+}
+
+pub fn part_one(grid: &Grid<bool>) -> Option<u64> {
+    let result = grid
+        .positions()
+        .filter(|&pos| grid[pos] && get_num_adjacent_elements(grid, pos) < 4)
+        .count();
+    Some(result as u64)
+}
+
+pub fn part_two(grid: &Grid<bool>) -> Option<u64> {
+    let mut grid = grid.clone();
+    let mut removed = 0;
+    loop {
+        match remove_accessible_elements(&mut grid) {
+            0 => return Some(removed as u64),
+            n => removed += n,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(43));
+    }
+}
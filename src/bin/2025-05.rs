@@ -0,0 +1,70 @@
+advent_of_code::solution!(2025, 5);
+
+use advent_of_code::{
+    interval::IntervalSet,
+    parse::{ParseError, Span},
+};
+
+// Parse the inclusive ranges (each line is "a-b") of the first section
+fn parse_ranges(section: Span) -> Result<IntervalSet<u64>, ParseError> {
+    section
+        .lines()
+        .map(|line| {
+            let (start, end) = line.split_once("-")?;
+            Ok(start.parse()?..=end.parse()?)
+        })
+        .collect()
+}
+
+// Split the input into the section of ranges and the section of items
+fn parse_sections(input: &str) -> Result<(Span<'_>, Span<'_>), ParseError> {
+    let (ranges, items) = Span::new(input).split_once("\n\n")?;
+    Ok((ranges.trim(), items.trim()))
+}
+
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let (ranges, items) = parse_sections(input)?;
+    let ranges = parse_ranges(ranges)?;
+    // Now get our items to check
+    let items = items
+        .lines()
+        .map(|line| line.parse::<u64>())
+        .collect::<Result<Vec<u64>, _>>()?;
+    let items_in_ranges = items
+        .into_iter()
+        .filter(|&item| ranges.contains(item))
+        .count();
+
+    Ok(items_in_ranges as u64)
+}
+
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let (ranges, _) = parse_sections(input)?;
+    let ranges = parse_ranges(ranges)?;
+
+    // Overlapping ranges are merged, so the covered length counts every ID once
+    Ok(ranges.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 14);
+    }
+
+    #[test]
+    fn test_malformed_item() {
+        let error = part_one("3-5\n10-14\n\n1\n5x\n").unwrap_err();
+        assert_eq!(error.location(), (5, 1));
+    }
+}
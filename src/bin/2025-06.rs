@@ -0,0 +1,157 @@
+use advent_of_code::{
+    grid::Grid,
+    parse::{ParseError, Span},
+};
+
+advent_of_code::solution!(2025, 6);
+
+#[derive(Debug)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    fn parse(word: Span) -> Result<Self, ParseError> {
+        match word.as_str() {
+            "+" => Ok(Operator::Add),
+            "*" => Ok(Operator::Multiply),
+            _ => Err(word.error(format!("unexpected operator `{}`", word.as_str()))),
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let input = Span::new(input.trim_end());
+    let mut data: Vec<Vec<u64>> = Vec::new();
+    let mut operators: Vec<Operator> = Vec::new();
+    for line in input.lines() {
+        let words = line.split_whitespace().collect::<Vec<Span>>();
+        if matches!(words.first().map(Span::as_str), Some("+" | "*")) {
+            operators = words
+                .iter()
+                .map(|&w| Operator::parse(w))
+                .collect::<Result<Vec<Operator>, ParseError>>()?;
+        } else {
+            // We have a row of numbers, so we can convert to a vec of ints
+            let numbers = words
+                .iter()
+                .map(|w| w.parse::<u64>())
+                .collect::<Result<Vec<u64>, ParseError>>()?;
+            if data
+                .first()
+                .is_some_and(|first| first.len() != numbers.len())
+            {
+                return Err(line.error(format!("expected {} numbers", data[0].len())));
+            }
+            data.push(numbers);
+        }
+    }
+    // So we now have a grid of data and a list of operators
+    let Some(first) = data.first() else {
+        return Err(input.error("expected rows of numbers"));
+    };
+    if operators.len() != first.len() {
+        return Err(input.error(format!("expected a row of {} operators", first.len())));
+    }
+    // We need to loop over columns and apply the operators to each column
+    let mut col_results: Vec<u64> = first.clone();
+    for row in &data[1..] {
+        for (col, value) in row.iter().enumerate() {
+            match &operators[col] {
+                Operator::Add => {
+                    col_results[col] += value;
+                }
+                Operator::Multiply => {
+                    col_results[col] *= value;
+                }
+            }
+        }
+    }
+
+    // Now our final result is the sum of all column results
+    Ok(col_results.iter().sum::<u64>())
+}
+
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    // Split the input into the last line and the grid of digits above it
+    let input = Span::new(input.trim_end_matches('\n'));
+    let Some(split) = input.as_str().rfind('\n') else {
+        return Err(input.error("expected rows of numbers above the operators"));
+    };
+    let sheet = Grid::parse(input.slice(0..split), |ch| ch)?;
+    let operator_line = input.slice(split + 1..input.as_str().len());
+    let operators = operator_line
+        .split_whitespace()
+        .map(Operator::parse)
+        .collect::<Result<Vec<Operator>, ParseError>>()?;
+
+    let mut operands: Vec<Vec<u64>> = Vec::new();
+    let mut current_operands: Vec<u64> = Vec::new();
+    for col in sheet.columns() {
+        // join the chars into a string
+        let s = col.collect::<String>();
+        if let Ok(num) = s.trim().parse::<u64>() {
+            current_operands.push(num);
+        } else {
+            // We reached the end of the operands for this column
+            operands.push(current_operands);
+            current_operands = Vec::new();
+        }
+    }
+    // push the last operands
+    if !current_operands.is_empty() {
+        operands.push(current_operands);
+    }
+    if operands.len() != operators.len() {
+        return Err(operator_line.error(format!("expected {} operators", operands.len())));
+    }
+    let result = operands
+        .iter()
+        .enumerate()
+        .map(|(i, ops)| {
+            let op = &operators[i];
+            match op {
+                Operator::Add => ops.iter().sum::<u64>(),
+                Operator::Multiply => ops.iter().product::<u64>(),
+            }
+        })
+        .sum::<u64>();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 4277556);
+    }
+
+    #[test]
+    fn test_malformed_worksheet() {
+        let error = part_one("123 328\n 45 6x\n*   +\n").unwrap_err();
+        assert_eq!(error.location(), (2, 5));
+
+        let error = part_one("123 328\n 45 64\n*   -\n").unwrap_err();
+        assert_eq!(error.location(), (3, 5));
+
+        let error = part_one("123 328\n 45\n*   +\n").unwrap_err();
+        assert_eq!(error.location(), (2, 1));
+
+        let error = part_two("123 328\n 45\n*   +\n").unwrap_err();
+        assert_eq!(error.location(), (2, 1));
+
+        let error = part_two("123 328\n 45  64\n*\n").unwrap_err();
+        assert_eq!(error.location(), (3, 1));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 3263827);
+    }
+}
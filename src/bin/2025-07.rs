@@ -0,0 +1,66 @@
+use std::collections::{HashMap, HashSet};
+
+use advent_of_code::{grid::Grid, parse::ParseError};
+
+advent_of_code::solution!(2025, 7, parse);
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |ch| ch)
+}
+
+pub fn part_one(manifold: &Grid<char>) -> Option<u64> {
+    // Look for char `S` to start our beam
+    let (start_row, start_col) = manifold.find(|&ch| ch == 'S')?;
+    let mut beam_cols: HashSet<usize> = HashSet::from([start_col]);
+    let mut num_splits = 0;
+    for row in manifold.rows().skip(start_row + 1) {
+        // If we find a '^' on a column in beam_cols, we have a split
+        for (col, &ch) in row.iter().enumerate() {
+            if ch == '^' && beam_cols.contains(&col) {
+                num_splits += 1;
+                // remove the column from beam_cols and replace it with col-1 and col+1
+                beam_cols.remove(&col);
+                beam_cols.insert(col - 1);
+                beam_cols.insert(col + 1);
+            }
+        }
+    }
+    Some(num_splits)
+}
+
+pub fn part_two(manifold: &Grid<char>) -> Option<u64> {
+    // Now, we don't just track beams, we have to count number that might overlap
+    let (start_row, start_col) = manifold.find(|&ch| ch == 'S')?;
+    let mut beam_cols: HashMap<usize, usize> = HashMap::from([(start_col, 1)]);
+    for row in manifold.rows().skip(start_row + 1) {
+        // If we find a '^' on a column in beam_cols, we have a split
+        for (col, &ch) in row.iter().enumerate() {
+            if ch == '^'
+                && let Some(count) = beam_cols.remove(&col)
+            {
+                *beam_cols.entry(col - 1).or_insert(0) += count;
+                *beam_cols.entry(col + 1).or_insert(0) += count;
+            }
+        }
+    }
+    Some(beam_cols.values().sum::<usize>() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(40));
+    }
+}
@@ -0,0 +1,112 @@
+use advent_of_code::{
+    parse::{ParseError, Span},
+    spatial::KdTree,
+    union_find::kruskal,
+};
+
+advent_of_code::solution!(2025, 8, parse);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JunctionBox {
+    x: u64,
+    y: u64,
+    z: u64,
+}
+
+// Parse one junction box per line, e.g. "162,817,812"
+fn parse(input: &str) -> Result<Vec<JunctionBox>, ParseError> {
+    Span::new(input.trim_end())
+        .lines()
+        .map(|line| match line.parse_list::<u64>(",")?[..] {
+            [x, y, z] => Ok(JunctionBox { x, y, z }),
+            _ => Err(line.error("expected three coordinates")),
+        })
+        .collect()
+}
+
+// Index of the junction boxes, to find the closest pairs without computing all distances
+fn build_tree(junction_boxes: &[JunctionBox]) -> KdTree<3> {
+    KdTree::new(
+        junction_boxes
+            .iter()
+            .map(|b| [b.x as i64, b.y as i64, b.z as i64])
+            .collect(),
+    )
+}
+
+// Pairs of junction boxes, closest first
+fn get_potential_connections(tree: &KdTree<3>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    tree.pairs().map(|(a, b, _distance)| (a, b))
+}
+
+fn connect_closest_n(junction_boxes: &[JunctionBox], number_to_connect: usize) -> u64 {
+    let tree = build_tree(junction_boxes);
+    let potential_connections = get_potential_connections(&tree);
+
+    let mut connections = kruskal(junction_boxes.len(), potential_connections);
+    connections.by_ref().take(number_to_connect).for_each(drop);
+
+    // Now multiply together the sizes of the biggest 3 circuits
+    connections
+        .union_find()
+        .component_sizes()
+        .iter()
+        .map(|&size| size as u64)
+        .take(3)
+        .product()
+}
+
+fn connect_until_one_circuit(junction_boxes: &[JunctionBox]) -> u64 {
+    let tree = build_tree(junction_boxes);
+    let potential_connections = get_potential_connections(&tree);
+
+    // The number we want to return is the x coordinates of the last two connected
+    // junctions multiplied together
+    kruskal(junction_boxes.len(), potential_connections)
+        .find(|connection| connection.components == 1)
+        .map_or(0, |connection| {
+            let (a, b) = connection.edge;
+            junction_boxes[a].x * junction_boxes[b].x
+        })
+}
+
+// The parts take what `parse` returns, so they can not take a slice
+#[allow(clippy::ptr_arg)]
+pub fn part_one(junction_boxes: &Vec<JunctionBox>) -> Option<u64> {
+    Some(connect_closest_n(junction_boxes, 1000))
+}
+
+#[allow(clippy::ptr_arg)]
+pub fn part_two(junction_boxes: &Vec<JunctionBox>) -> Option<u64> {
+    Some(connect_until_one_circuit(junction_boxes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = connect_closest_n(
+            &parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap(),
+            10,
+        );
+        assert_eq!(result, 40);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(25272));
+    }
+
+    #[test]
+    fn test_malformed_junction_box() {
+        let error = parse("162,817,812\n57,618\n").unwrap_err();
+        assert_eq!(error.location(), (2, 1));
+
+        let error = parse("162,817,812\n57,-618,57\n").unwrap_err();
+        assert_eq!(error.location(), (2, 4));
+    }
+}
@@ -0,0 +1,225 @@
+use advent_of_code::{
+    geometry::{Polygon, Raster},
+    parse::{ParseError, Span},
+};
+
+advent_of_code::solution!(2025, 9, parse);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Corner {
+    x: i64,
+    y: i64,
+}
+
+impl Corner {
+    fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+    fn area_with_other(&self, other: &Corner) -> u64 {
+        let width = (other.x - self.x).abs() as u64 + 1;
+        let height = (other.y - self.y).abs() as u64 + 1;
+        width * height
+    }
+}
+
+// The polygon traced by the corners, in the order they are listed.
+// None if a corner is not in the row or column of the previous one.
+fn trace_shape(corners: &[Corner]) -> Option<Polygon> {
+    Polygon::new(corners.iter().map(|c| (c.x, c.y)).collect())
+}
+
+// Error pointing at the line of the first corner that does not continue the shape
+fn shape_error(input: &str, corners: &[Corner]) -> ParseError {
+    let input = Span::new(input.trim_end());
+    let n = corners.len();
+    if n < 4 {
+        return input.error(format!("expected at least 4 corners, found {n}"));
+    }
+
+    // the closing edge back to the first corner is checked last
+    let disconnected = (1..n).chain([0]).find(|&i| {
+        let (prev, corner) = (corners[(i + n - 1) % n], corners[i]);
+        corner == prev || (corner.x != prev.x && corner.y != prev.y)
+    });
+    let line = disconnected
+        .and_then(|i| input.lines().nth(i))
+        .unwrap_or(input);
+    line.error("corner is not in the row or column of the previous corner")
+}
+
+// Return true if any tile of the rectangle between two opposite corners is outside the shape
+fn rectangle_goes_outside_bounds(c1: &Corner, c2: &Corner, shape: &Raster) -> bool {
+    !shape.contains_rect((c1.x, c1.y), (c2.x, c2.y))
+}
+
+// Parse one corner per line, e.g. "7,1", and the shape they trace
+fn parse(input: &str) -> Result<(Vec<Corner>, Polygon), ParseError> {
+    let corners = Span::new(input.trim_end())
+        .lines()
+        .map(|line| {
+            let (x, y) = line.split_once(",")?;
+            Ok(Corner::new(x.parse()?, y.parse()?))
+        })
+        .collect::<Result<Vec<Corner>, ParseError>>()?;
+    let shape = trace_shape(&corners).ok_or_else(|| shape_error(input, &corners))?;
+    Ok((corners, shape))
+}
+
+pub fn part_one((corners, _): &(Vec<Corner>, Polygon)) -> Option<u64> {
+    // Find the biggest area formed between any two corners
+    let mut biggest_area: u64 = 0;
+    for i in 0..corners.len() {
+        for j in i + 1..corners.len() {
+            let area = corners[i].area_with_other(&corners[j]);
+            if area > biggest_area {
+                biggest_area = area;
+            }
+        }
+    }
+    Some(biggest_area)
+}
+
+pub fn part_two((corners, shape): &(Vec<Corner>, Polygon)) -> Option<u64> {
+    // Find the biggest area formed between any two corners that stays inside the shape
+    let shape = shape.raster();
+    let mut biggest_area: u64 = 0;
+    for i in 0..corners.len() {
+        for j in i + 1..corners.len() {
+            if rectangle_goes_outside_bounds(&corners[i], &corners[j], &shape) {
+                continue;
+            }
+
+            let area = corners[i].area_with_other(&corners[j]);
+            if area > biggest_area {
+                biggest_area = area;
+            }
+        }
+    }
+    Some(biggest_area)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(50));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(24));
+    }
+
+    #[test]
+    fn test_malformed_corner() {
+        let error = parse("7,1\n11,1\n11:7\n").unwrap_err();
+        assert_eq!(error.location(), (3, 1));
+
+        let error = parse("7,1\n11,1\n11,7\n9,8\n").unwrap_err();
+        assert_eq!(error.location(), (4, 1));
+    }
+
+    #[test]
+    fn test_wrong_shape_with_real_input() {
+        let (_, shape) = parse(&advent_of_code::template::read_file("inputs", PUZZLE)).unwrap();
+
+        // From debug output, we know a rectangle that should not be valid
+        let c1 = Corner::new(17454, 85504);
+        let c2 = Corner::new(82409, 14643);
+
+        assert!(rectangle_goes_outside_bounds(&c1, &c2, &shape.raster()));
+    }
+
+    #[test]
+    fn test_point_inside_polygon() {
+        let mut polygon = Vec::new();
+        polygon.push(Corner::new(1, 1));
+        polygon.push(Corner::new(5, 1));
+        polygon.push(Corner::new(5, 5));
+        polygon.push(Corner::new(1, 5));
+
+        let shape = trace_shape(&polygon).unwrap().raster();
+        assert!(shape.contains((3, 3)));
+        assert!(!shape.contains((6, 3)));
+    }
+
+    #[test]
+    fn test_rectangle_goes_outside_bounds() {
+        let mut shape = Vec::new();
+
+        //   1234567
+        //01 .......
+        //02 .......
+        //03 .......
+        //04 .#.#...
+        //05 ##.##..
+        //06 .......
+        //07 .......
+        //08 .......
+        //09 .......
+        //10 #...#..
+        //11 .......
+        //
+        shape.push(Corner::new(1, 5));
+        shape.push(Corner::new(2, 5));
+        shape.push(Corner::new(2, 4));
+        shape.push(Corner::new(4, 4));
+        shape.push(Corner::new(4, 5));
+        shape.push(Corner::new(5, 5));
+        shape.push(Corner::new(5, 10));
+        shape.push(Corner::new(1, 10));
+
+        let shape = trace_shape(&shape).unwrap().raster();
+
+        let c1 = Corner::new(2, 5);
+        let c2 = Corner::new(5, 10);
+        assert!(!rectangle_goes_outside_bounds(&c1, &c2, &shape));
+
+        let c1 = Corner::new(1, 5);
+        let c2 = Corner::new(4, 4);
+        assert!(rectangle_goes_outside_bounds(&c1, &c2, &shape));
+    }
+
+    #[test]
+    fn test_rectangle_goes_outside_concave() {
+        let mut shape = Vec::new();
+
+        //   1234567
+        //01 .......
+        //02 .......
+        //03 .......
+        //04 .......
+        //05 ##.##..
+        //06 .......
+        //07 .#.#...
+        //08 .......
+        //09 .......
+        //10 #...#..
+        //11 .......
+        //
+        shape.push(Corner::new(1, 5));
+        shape.push(Corner::new(2, 5));
+        shape.push(Corner::new(2, 7));
+        shape.push(Corner::new(4, 7));
+        shape.push(Corner::new(4, 5));
+        shape.push(Corner::new(5, 5));
+        shape.push(Corner::new(5, 10));
+        shape.push(Corner::new(1, 10));
+
+        let shape = trace_shape(&shape).unwrap().raster();
+
+        let c1 = Corner::new(2, 5);
+        let c2 = Corner::new(5, 10);
+        assert!(rectangle_goes_outside_bounds(&c1, &c2, &shape));
+
+        let c1 = Corner::new(1, 5);
+        let c2 = Corner::new(2, 7);
+        assert!(!rectangle_goes_outside_bounds(&c1, &c2, &shape));
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use good_lp::*;
 use petgraph::graph;
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2025, 10);
 
 #[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 struct Lights {
@@ -283,13 +283,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(33));
     }
 
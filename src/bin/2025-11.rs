@@ -1,4 +1,4 @@
-advent_of_code::solution!(11);
+advent_of_code::solution!(2025, 11);
 
 use petgraph::algo::{all_simple_paths, toposort};
 use petgraph::graph::{DiGraph, NodeIndex};
@@ -92,7 +92,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(5));
     }
 
@@ -0,0 +1,122 @@
+use advent_of_code::{
+    grid::Grid,
+    parse::{ParseError, Span},
+    template::Answer,
+};
+
+advent_of_code::solution!(2025, 12, parse);
+
+#[derive(Debug, Clone)]
+pub struct Shape {
+    cells: Grid<bool>,
+}
+
+impl Shape {
+    fn from_pattern(pattern: Span) -> Result<Self, ParseError> {
+        let cells = Grid::parse(pattern, |ch| ch == '#')?;
+        Ok(Shape { cells })
+    }
+
+    // Number of cells the shape occupies
+    fn area(&self) -> usize {
+        self.cells.iter().filter(|&(_, &filled)| filled).count()
+    }
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    i: usize,
+    j: usize,
+    shape_counts: Vec<usize>,
+}
+
+impl Problem {
+    // Parse a line with format "WxH: n0 n1 n2 n3 n4 n5"
+    fn parse(line: Span) -> Result<Self, ParseError> {
+        let (dimensions, counts) = line.split_once(": ")?;
+        let (width, height) = dimensions.split_once("x")?;
+        let counts = counts
+            .split_whitespace()
+            .map(|num| num.parse::<usize>())
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        Ok(Problem {
+            i: width.parse()?,
+            j: height.parse()?,
+            shape_counts: counts,
+        })
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<Shape>, Vec<Problem>), ParseError> {
+    let mut shapes = Vec::new();
+    let mut problems = Vec::new();
+
+    for block in Span::new(input.trim_end()).blocks() {
+        match block.split_once(":\n") {
+            // Found a shape marker like "0:", the rest of the block is its pattern
+            Ok((_, pattern)) => shapes.push(Shape::from_pattern(pattern)?),
+            // Otherwise the block lists the problems
+            Err(_) => {
+                for line in block.lines() {
+                    let problem = Problem::parse(line)?;
+                    if problem.shape_counts.len() > shapes.len() {
+                        return Err(
+                            line.error(format!("expected at most {} shape counts", shapes.len()))
+                        );
+                    }
+                    problems.push(problem);
+                }
+            }
+        }
+    }
+
+    Ok((shapes, problems))
+}
+
+pub fn part_one((shapes, problems): &(Vec<Shape>, Vec<Problem>)) -> Option<u64> {
+    // Calculate actual cell count for each shape
+    let shape_cells: Vec<usize> = shapes.iter().map(Shape::area).collect();
+
+    let count = problems
+        .iter()
+        .filter(|problem| {
+            let area = problem.i * problem.j;
+
+            // Calculate total cells needed based on actual shape sizes
+            let total_cells_needed: usize = problem.shape_counts
+                .iter()
+                .enumerate()
+                .map(|(idx, &count)| count * shape_cells[idx])
+                .sum();
+
+            // Problem is solvable if cells needed <= area
+            total_cells_needed <= area
+        })
+        .count();
+
+    count.try_into().ok()
+}
+
+pub fn part_two(_input: &(Vec<Shape>, Vec<Problem>)) -> Answer {
+    Answer::NotApplicable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_problem() {
+        let error = parse("0:\n##\n\n4x4: 0 x\n").unwrap_err();
+        assert_eq!(error.location(), (4, 8));
+
+        let error = parse("0:\n##\n\n4x4: 0\n4 4: 1\n").unwrap_err();
+        assert_eq!(error.location(), (5, 1));
+
+        let error = parse("0:\n##\n\n4x4: 1 2\n").unwrap_err();
+        assert_eq!(error.location(), (4, 1));
+
+        let error = parse("0:\n##\n#\n\n4x4: 1\n").unwrap_err();
+        assert_eq!(error.location(), (3, 1));
+    }
+}
@@ -0,0 +1,341 @@
+//! Orthogonal polygons on a grid of tiles, e.g. a loop of red tiles on a floor.
+//!
+//! Vertices are tiles `(x, y)`, and consecutive vertices share a row or a column. The polygon covers
+//! the tiles on its boundary and the ones enclosed by it. Puzzle coordinates are usually far too large
+//! to rasterize tile by tile, so [`Raster`] compresses them: only the rows and columns of vertices
+//! are kept, and the runs of tiles between them collapse into one cell each. Every cell is either
+//! fully covered by the polygon or not at all.
+//!
+//! ```
+//! use advent_of_code::geometry::Polygon;
+//!
+//! let polygon = Polygon::new(vec![(0, 0), (100, 0), (100, 10), (50, 10), (50, 60), (0, 60)]).unwrap();
+//! let raster = polygon.raster();
+//! assert!(raster.contains_rect((0, 0), (50, 60)));
+//! assert!(!raster.contains_rect((0, 0), (100, 60)));
+//! ```
+use crate::grid::Grid;
+
+/// A tile as `(x, y)`.
+pub type Point = (i64, i64);
+
+/// A closed polygon whose edges are all horizontal or vertical.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Creates a polygon from its vertices in order, the last one connects back to the first one.
+    /// Returns `None` for less than four vertices, or if consecutive vertices are equal or do not
+    /// share a row or column.
+    pub fn new(vertices: Vec<Point>) -> Option<Self> {
+        let polygon = Self { vertices };
+
+        let is_orthogonal = polygon
+            .edges()
+            .all(|(a, b)| a != b && (a.0 == b.0 || a.1 == b.1));
+
+        (polygon.vertices.len() >= 4 && is_orthogonal).then_some(polygon)
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Pairs of consecutive vertices, including the one from the last back to the first vertex.
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Area enclosed by the edges through the centers of the tiles, by the shoelace formula.
+    pub fn area(&self) -> u64 {
+        let twice: i128 = self
+            .edges()
+            .map(|(a, b)| a.0 as i128 * b.1 as i128 - b.0 as i128 * a.1 as i128)
+            .sum();
+        (twice.unsigned_abs() / 2) as u64
+    }
+
+    /// Length of the edges through the centers of the tiles, which is the number of boundary tiles.
+    pub fn perimeter(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            .sum()
+    }
+
+    /// Number of tiles covered by the polygon, including its boundary. By Pick's theorem, the area
+    /// equals the tiles inside plus half of the boundary tiles, minus one.
+    pub fn tile_count(&self) -> u64 {
+        self.area() + self.perimeter() / 2 + 1
+    }
+
+    /// Rasterizes the polygon on its compressed coordinates.
+    pub fn raster(&self) -> Raster {
+        Raster::new(self)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Coordinate compression of one axis: splits it into cells at a set of coordinates.
+///
+/// Every coordinate of the set gets a cell of its own, and the coordinates between two of them share
+/// one cell. Everything before the smallest and after the largest coordinate is a cell too, so any
+/// coordinate maps to a cell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompressedAxis {
+    /// First coordinate of each cell, ascending.
+    starts: Vec<i64>,
+}
+
+impl CompressedAxis {
+    pub fn new(coordinates: impl IntoIterator<Item = i64>) -> Self {
+        let mut coordinates: Vec<i64> = coordinates.into_iter().collect();
+        coordinates.sort_unstable();
+        coordinates.dedup();
+
+        let mut starts = vec![i64::MIN];
+        for (i, &x) in coordinates.iter().enumerate() {
+            if x > *starts.last().unwrap() {
+                starts.push(x);
+            }
+            // the run up to the next coordinate, which is empty if it follows right away.
+            match (x.checked_add(1), coordinates.get(i + 1)) {
+                (Some(gap), Some(&next)) if gap < next => starts.push(gap),
+                (Some(gap), None) => starts.push(gap),
+                _ => {}
+            }
+        }
+
+        Self { starts }
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.starts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    /// Cell of a coordinate.
+    pub fn index(&self, coordinate: i64) -> usize {
+        self.starts.partition_point(|&start| start <= coordinate) - 1
+    }
+
+    /// First coordinate of a cell.
+    pub fn start(&self, index: usize) -> i64 {
+        self.starts[index]
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The tiles covered by a [`Polygon`], on compressed coordinates.
+#[derive(Clone, Debug)]
+pub struct Raster {
+    xs: CompressedAxis,
+    ys: CompressedAxis,
+    /// Whether each cell is covered, indexed by `(y, x)` cell.
+    inside: Grid<bool>,
+    /// Number of cells that are not covered above and left of each cell, exclusive.
+    outside_sums: Grid<u32>,
+}
+
+impl Raster {
+    pub fn new(polygon: &Polygon) -> Self {
+        let xs = CompressedAxis::new(polygon.vertices.iter().map(|&(x, _)| x));
+        let ys = CompressedAxis::new(polygon.vertices.iter().map(|&(_, y)| y));
+
+        let mut boundary = Grid::new(xs.len(), ys.len(), false);
+        for ((ax, ay), (bx, by)) in polygon.edges() {
+            let (x0, x1) = (xs.index(ax.min(bx)), xs.index(ax.max(bx)));
+            let (y0, y1) = (ys.index(ay.min(by)), ys.index(ay.max(by)));
+            for y in y0..=y1 {
+                for x in x0..=x1 {
+                    boundary[(y, x)] = true;
+                }
+            }
+        }
+
+        // the first cell of both axes starts at `i64::MIN`, which is always outside.
+        let mut inside = Grid::new(xs.len(), ys.len(), true);
+        for pos in boundary.flood_fill((0, 0), |_, &next| !next) {
+            inside[pos] = false;
+        }
+
+        let mut outside_sums = Grid::new(xs.len() + 1, ys.len() + 1, 0);
+        for (y, x) in inside.positions() {
+            outside_sums[(y + 1, x + 1)] = outside_sums[(y, x + 1)] + outside_sums[(y + 1, x)]
+                - outside_sums[(y, x)]
+                + u32::from(!inside[(y, x)]);
+        }
+
+        Self {
+            xs,
+            ys,
+            inside,
+            outside_sums,
+        }
+    }
+
+    /// Whether a tile is covered by the polygon.
+    pub fn contains(&self, (x, y): Point) -> bool {
+        self.inside[(self.ys.index(y), self.xs.index(x))]
+    }
+
+    /// Whether every tile of the rectangle between two opposite corners is covered by the polygon,
+    /// in constant time.
+    pub fn contains_rect(&self, a: Point, b: Point) -> bool {
+        let (x0, x1) = (self.xs.index(a.0.min(b.0)), self.xs.index(a.0.max(b.0)));
+        let (y0, y1) = (self.ys.index(a.1.min(b.1)), self.ys.index(a.1.max(b.1)));
+        let sums = &self.outside_sums;
+
+        sums[(y1 + 1, x1 + 1)] + sums[(y0, x0)] == sums[(y0, x1 + 1)] + sums[(y1 + 1, x0)]
+    }
+
+    /// Covered cells, indexed by `(y, x)` cell of the compressed axes.
+    pub fn cells(&self) -> &Grid<bool> {
+        &self.inside
+    }
+
+    pub fn x_axis(&self) -> &CompressedAxis {
+        &self.xs
+    }
+
+    pub fn y_axis(&self) -> &CompressedAxis {
+        &self.ys
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{CompressedAxis, Polygon};
+
+    /// An L shape with a notch:
+    ///
+    /// ```text
+    /// #####.
+    /// #...#.
+    /// #.###.
+    /// #.#...
+    /// ###...
+    /// ```
+    fn polygon() -> Polygon {
+        Polygon::new(vec![(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]).unwrap()
+    }
+
+    /// Tiles of a polygon, rasterized by ray casting through every tile of its bounding box.
+    fn naive_tiles(polygon: &Polygon) -> Vec<(i64, i64)> {
+        let on_boundary = |(x, y): (i64, i64)| {
+            polygon.edges().any(|(a, b)| {
+                (a.0.min(b.0)..=a.0.max(b.0)).contains(&x)
+                    && (a.1.min(b.1)..=a.1.max(b.1)).contains(&y)
+            })
+        };
+        let crossings = |(x, y): (i64, i64)| {
+            polygon
+                .edges()
+                .filter(|(a, b)| a.0 == b.0 && a.0 > x && y >= a.1.min(b.1) && y < a.1.max(b.1))
+                .count()
+        };
+
+        let (min_x, max_x) = (
+            polygon.vertices().iter().map(|p| p.0).min().unwrap(),
+            polygon.vertices().iter().map(|p| p.0).max().unwrap(),
+        );
+        let (min_y, max_y) = (
+            polygon.vertices().iter().map(|p| p.1).min().unwrap(),
+            polygon.vertices().iter().map(|p| p.1).max().unwrap(),
+        );
+
+        (min_y..=max_y)
+            .flat_map(|y| (min_x..=max_x).map(move |x| (x, y)))
+            .filter(|&p| on_boundary(p) || crossings(p) % 2 == 1)
+            .collect()
+    }
+
+    #[test]
+    fn rejects_non_orthogonal_polygons() {
+        assert!(Polygon::new(vec![(0, 0), (4, 0), (4, 2)]).is_none());
+        assert!(Polygon::new(vec![(0, 0), (4, 0), (4, 2), (1, 3)]).is_none());
+        assert!(Polygon::new(vec![(0, 0), (0, 0), (4, 0), (4, 2), (0, 2)]).is_none());
+    }
+
+    #[test]
+    fn measures_polygons() {
+        let polygon = polygon();
+        assert_eq!(polygon.area(), 12);
+        assert_eq!(polygon.perimeter(), 16);
+        assert_eq!(polygon.tile_count(), 21);
+        assert_eq!(polygon.tile_count(), naive_tiles(&polygon).len() as u64);
+    }
+
+    #[test]
+    fn compresses_coordinates() {
+        let axis = CompressedAxis::new([10, 3, 4, 10]);
+        // ..=2, 3, 4, 5..=9, 10, 11..
+        assert_eq!(axis.len(), 6);
+        assert_eq!(axis.index(i64::MIN), 0);
+        assert_eq!(axis.index(3), 1);
+        assert_eq!(axis.index(4), 2);
+        assert_eq!(axis.index(7), 3);
+        assert_eq!(axis.index(10), 4);
+        assert_eq!(axis.index(i64::MAX), 5);
+        assert_eq!(axis.start(3), 5);
+    }
+
+    #[test]
+    fn rasterizes_polygons() {
+        let polygon = polygon();
+        let raster = polygon.raster();
+        let tiles = naive_tiles(&polygon);
+
+        for y in -1..=5 {
+            for x in -1..=5 {
+                assert_eq!(raster.contains((x, y)), tiles.contains(&(x, y)), "{x},{y}");
+            }
+        }
+
+        assert!(raster.contains_rect((0, 0), (4, 2)));
+        assert!(raster.contains_rect((2, 4), (0, 0)));
+        assert!(!raster.contains_rect((0, 0), (4, 4)));
+        assert!(!raster.contains_rect((1, 1), (3, 3)));
+        assert!(!raster.contains_rect((5, 0), (5, 0)));
+    }
+
+    #[test]
+    fn matches_naive_rasterization_for_spread_out_coordinates() {
+        // a comb whose teeth are separated by long runs of tiles.
+        let mut vertices = vec![(0, 0)];
+        for tooth in 0..4 {
+            let x = tooth * 1000;
+            vertices.extend([(x + 100, 0), (x + 100, 500), (x + 1000, 500), (x + 1000, 0)]);
+        }
+        vertices.extend([(4100, 0), (4100, 2000), (0, 2000)]);
+        vertices.dedup();
+        let polygon = Polygon::new(vertices).unwrap();
+        let raster = polygon.raster();
+
+        let tiles: u64 = raster
+            .cells()
+            .iter()
+            .filter(|&(_, &inside)| inside)
+            .map(|((y, x), _)| {
+                let width = raster.x_axis().start(x + 1) - raster.x_axis().start(x);
+                let height = raster.y_axis().start(y + 1) - raster.y_axis().start(y);
+                (width * height) as u64
+            })
+            .sum();
+        assert_eq!(tiles, polygon.tile_count());
+
+        assert!(raster.contains_rect((0, 500), (4100, 2000)));
+        assert!(!raster.contains_rect((0, 0), (1000, 500)));
+        assert!(raster.contains_rect((100, 0), (100, 2000)));
+    }
+}
@@ -0,0 +1,468 @@
+//! Dense two-dimensional grids, as found in many puzzle inputs.
+//!
+//! Cells are addressed by `(row, column)`, starting at the top left. Parsing reports ragged rows and
+//! unexpected characters as [`ParseError`]s pointing at the offending position of the input.
+//!
+//! ```
+//! use advent_of_code::grid::Grid;
+//!
+//! let grid = Grid::parse("#..\n.#.\n", |c| c == '#').unwrap();
+//! assert_eq!((grid.height(), grid.width()), (2, 3));
+//! assert_eq!(grid.neighbours8((0, 0)).filter(|&pos| grid[pos]).count(), 1);
+//! ```
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::{ParseError, Span};
+
+/// Position of a cell as `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// Offsets of the orthogonal neighbours of a cell: up, right, down, left.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of the orthogonal and diagonal neighbours of a cell, clockwise from the top left.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid where every cell has the same value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its rows. Returns `None` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses every character of the input into a cell, one line per row. The input can also be a
+    /// [`Span`] of a larger input, e.g. a block, so that errors point into the whole input.
+    pub fn parse<'a>(
+        input: impl Into<Span<'a>>,
+        mut cell: impl FnMut(char) -> T,
+    ) -> Result<Self, ParseError> {
+        Self::try_parse(input, |c| Some(cell(c)))
+    }
+
+    /// Parses every character of the input into a cell, one line per row. Fails for characters that
+    /// `cell` returns `None` for.
+    pub fn try_parse<'a>(
+        input: impl Into<Span<'a>>,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let input = input.into();
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        let rows = input.slice(0..input.as_str().trim_end_matches('\n').len());
+        for line in rows.lines() {
+            let text = line.as_str();
+            let mut line_width = 0;
+
+            for (i, c) in text.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    line.slice(i..i + c.len_utf8())
+                        .error(format!("unexpected `{c}` in grid"))
+                })?;
+                cells.push(value);
+                line_width += 1;
+            }
+
+            match width {
+                Some(width) if width != line_width => {
+                    return Err(line.error(format!(
+                        "expected a row of {width} cells, found {line_width}"
+                    )));
+                }
+                _ => width = Some(line_width),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// Position one step from `pos` in a direction, if it lies inside of the grid.
+    pub fn step(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Orthogonal neighbours of a cell that lie inside of the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS_4
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Orthogonal and diagonal neighbours of a cell that lie inside of the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS_8
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row by row, that matches a predicate.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics for a chunk size of 0, grids without columns have no cells anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} is outside of the grid");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Creates a grid of the same size by converting every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Creates a grid of the given size from the cell at each of its positions in this grid.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Rows become columns: the cell at `(row, col)` moves to `(col, row)`.
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(row, col)| (col, row))
+    }
+
+    pub fn rotated_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(height, self.width, |(row, col)| (height - 1 - col, row))
+    }
+
+    pub fn rotated_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, width, |(row, col)| (col, width - 1 - row))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flipped_horizontally(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(width, self.height, |(row, col)| (row, width - 1 - col))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flipped_vertically(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, height, |(row, col)| (height - 1 - row, col))
+    }
+
+    /// Cells reachable from `start` through orthogonal steps, where a step from one cell to a neighbour
+    /// is only taken if `connects` allows it. Returns positions in breadth-first order, starting with
+    /// `start`, or nothing if `start` lies outside of the grid.
+    pub fn flood_fill(&self, start: Pos, mut connects: impl FnMut(&T, &T) -> bool) -> Vec<Pos> {
+        if !self.contains(start) {
+            return vec![];
+        }
+
+        let mut seen = Grid::new(self.width, self.height, false);
+        let mut queue = VecDeque::from([start]);
+        let mut region = vec![];
+        seen[start] = true;
+
+        while let Some(pos) = queue.pop_front() {
+            region.push(pos);
+
+            for next in self.neighbours4(pos) {
+                if !seen[next] && connects(&self[pos], &self[next]) {
+                    seen[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        region
+    }
+
+    /// The region of orthogonally connected cells with the same value as `start`.
+    pub fn region(&self, start: Pos) -> Vec<Pos>
+    where
+        T: PartialEq,
+    {
+        self.flood_fill(start, |a, b| a == b)
+    }
+
+    /// Renders the grid as text, one line per row.
+    pub fn to_text(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows().take(self.height) {
+            text.extend(row.iter().map(&mut cell));
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    /// Panics if the position lies outside of the grid.
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is outside of the grid"))
+    }
+}
+
+/// Displays the grid one line per row, e.g. `Grid<char>` as it was parsed.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().take(self.height).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::parse::Span;
+
+    const INPUT: &str = "abc\ndef\n";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, |c| c).unwrap()
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(grid.to_text(|c| c.to_ascii_uppercase()), "ABC\nDEF\n");
+        assert_eq!(
+            Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]),
+            Some(grid)
+        );
+    }
+
+    #[test]
+    fn reports_malformed_rows() {
+        let error = Grid::parse("abc\nde\n", |c| c).unwrap_err();
+        assert_eq!(error.location(), (2, 1));
+
+        let error = Grid::try_parse("#.\n.x\n", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap_err();
+        assert_eq!(error.location(), (2, 2));
+        assert!(Grid::from_rows(vec![vec![1], vec![]]).is_none());
+
+        let input = Span::new("0:\n##\n\n1:\n##\n#\n");
+        let block = input.blocks().nth(1).unwrap().slice(3..7);
+        let error = Grid::parse(block, |c| c).unwrap_err();
+        assert_eq!(error.location(), (6, 1));
+    }
+
+    #[test]
+    fn finds_neighbours_inside_of_the_grid() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+        assert_eq!(
+            grid.neighbours8((1, 2)).collect::<Vec<_>>(),
+            vec![(0, 1), (0, 2), (1, 1)]
+        );
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(grid.find(|&c| c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(
+            grid.columns()
+                .map(|col| col.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = grid();
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotated_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotated_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flipped_horizontally().to_string(), "cba\nfed");
+        assert_eq!(grid.flipped_vertically().to_string(), "def\nabc");
+        assert_eq!(grid.rotated_clockwise().rotated_counter_clockwise(), grid);
+        assert_eq!(grid.map(|c| *c as u8 - b'a')[(1, 2)], 5);
+    }
+
+    #[test]
+    fn fills_regions() {
+        let grid = Grid::parse("aab\nabb\nccb\n", |c| c).unwrap();
+        assert_eq!(grid.region((0, 0)), vec![(0, 0), (0, 1), (1, 0)]);
+        assert_eq!(grid.region((0, 2)).len(), 4);
+        assert_eq!(grid.region((5, 5)), vec![]);
+
+        // cells can be connected by any rule, e.g. everything but walls.
+        let maze = Grid::parse("..#\n.#.\n", |c| c).unwrap();
+        let open = maze.flood_fill((0, 0), |_, next| *next != '#');
+        assert_eq!(open, vec![(0, 0), (0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn handles_empty_grids() {
+        let grid = Grid::parse("", |c| c).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(grid.to_string(), "");
+    }
+}
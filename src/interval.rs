@@ -0,0 +1,402 @@
+//! Sets of integers stored as disjoint inclusive ranges, e.g. ID ranges of a puzzle input.
+//!
+//! Overlapping and adjacent ranges are merged when they are inserted, so an [`IntervalSet`] always
+//! holds the fewest spans that cover its values. Spans are kept in a [`BTreeMap`] from start to end,
+//! which makes inserting, removing and looking up values O(log n) in the number of spans.
+//!
+//! ```
+//! use advent_of_code::interval::IntervalSet;
+//!
+//! let set: IntervalSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+//! assert_eq!(set.iter().collect::<Vec<_>>(), vec![3..=5, 10..=20]);
+//! assert_eq!(set.len(), 14);
+//! assert!(set.contains(17));
+//! ```
+use std::{collections::BTreeMap, fmt, ops::RangeInclusive};
+
+/// Integers that can be stored in an [`IntervalSet`].
+pub trait Bound: Copy + Ord {
+    /// The next larger value, `None` for the largest value of the type.
+    fn successor(self) -> Option<Self>;
+
+    /// The next smaller value, `None` for the smallest value of the type.
+    fn predecessor(self) -> Option<Self>;
+
+    /// Number of values from `start` to `end`, both inclusive. Saturates at [`u64::MAX`].
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),*) => {
+        $(
+            impl Bound for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u64 {
+                    let count = (end as i128 - start as i128) + 1;
+                    u64::try_from(count).unwrap_or(u64::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_bound!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers stored as disjoint, non-adjacent inclusive ranges.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Start of each span mapped to its end.
+    spans: BTreeMap<T, T>,
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            spans: BTreeMap::new(),
+        }
+    }
+
+    /// Whether the set contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Number of disjoint spans of the set.
+    pub fn span_count(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Number of values covered by the set. Saturates at [`u64::MAX`].
+    pub fn len(&self) -> u64 {
+        self.spans.iter().fold(0u64, |len, (&start, &end)| {
+            len.saturating_add(T::count(start, end))
+        })
+    }
+
+    /// The span that contains a value.
+    fn span_of(&self, value: T) -> Option<(T, T)> {
+        self.spans
+            .range(..=value)
+            .next_back()
+            .filter(|&(_, &end)| end >= value)
+            .map(|(&start, &end)| (start, end))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.span_of(value).is_some()
+    }
+
+    /// Whether every value of a range is in the set. Empty ranges are always contained.
+    pub fn contains_range(&self, range: RangeInclusive<T>) -> bool {
+        let (start, end) = range.into_inner();
+        start > end
+            || self
+                .span_of(start)
+                .is_some_and(|(_, span_end)| span_end >= end)
+    }
+
+    /// Adds the values of a range, merging it with the spans it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // a span that starts before the range can reach into it, or end right before it.
+        if let Some((&prev_start, &prev_end)) = self.spans.range(..=start).next_back()
+            && prev_end.successor().is_none_or(|next| next >= start)
+        {
+            if prev_end >= end {
+                return;
+            }
+            start = prev_start;
+        }
+
+        // spans that start inside of the range, or right after it, are absorbed.
+        let absorbed: Vec<(T, T)> = match end.successor() {
+            Some(next) => self.spans.range(start..=next),
+            None => self.spans.range(start..),
+        }
+        .map(|(&start, &end)| (start, end))
+        .collect();
+
+        for (span_start, span_end) in absorbed {
+            self.spans.remove(&span_start);
+            end = end.max(span_end);
+        }
+
+        self.spans.insert(start, end);
+    }
+
+    /// Removes the values of a range, splitting spans that reach past it.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let overlapping: Vec<(T, T)> = self
+            .span_of(start)
+            .into_iter()
+            .chain(
+                self.spans
+                    .range(start..=end)
+                    .map(|(&start, &end)| (start, end)),
+            )
+            .collect();
+
+        for (span_start, span_end) in overlapping {
+            self.spans.remove(&span_start);
+            if let Some(before) = start.predecessor()
+                && span_start < start
+            {
+                self.spans.insert(span_start, before);
+            }
+            if let Some(after) = end.successor()
+                && span_end > end
+            {
+                self.spans.insert(after, span_end);
+            }
+        }
+    }
+
+    /// The disjoint spans of the set, in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.spans.iter().map(|(&start, &end)| start..=end)
+    }
+
+    /// Values that are in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let (mut larger, smaller) = if self.span_count() >= other.span_count() {
+            (self.clone(), other)
+        } else {
+            (other.clone(), self)
+        };
+        larger.extend(smaller.iter());
+        larger
+    }
+
+    /// Values that are in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Self::new();
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+
+        // both sides are sorted, so the span that ends first can not overlap any later span.
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let start = *x.start().max(y.start());
+            let end = *x.end().min(y.end());
+            if start <= end {
+                result.spans.insert(start, end);
+            }
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        result
+    }
+
+    /// Values that are in this set but not in the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+}
+
+impl<T: Bound> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Bound> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Bound> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+/// Formats the set as its spans, e.g. `{3..=5, 10..=20}`.
+impl<T: Bound + fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::IntervalSet;
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        let mut set = IntervalSet::new();
+        set.insert(10u64..=14);
+        set.insert(3..=5);
+        set.insert(16..=20);
+        assert_eq!(set.span_count(), 3);
+
+        set.insert(15..=15);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3..=5, 10..=20]);
+
+        set.insert(6..=9);
+        set.insert(11..=12);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3..=20]);
+        assert_eq!(set.len(), 18);
+    }
+
+    #[test]
+    fn splits_spans_on_removal() {
+        let mut set: IntervalSet<i32> = [-5..=5, 10..=20].into_iter().collect();
+        set.remove(0..=12);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![-5..=-1, 13..=20]);
+        assert!(set.contains(-1));
+        assert!(!set.contains(0));
+        assert!(set.contains_range(14..=20));
+        assert!(!set.contains_range(12..=14));
+        assert_eq!(format!("{set:?}"), "{-5..=-1, 13..=20}");
+    }
+
+    #[test]
+    fn handles_bounds_of_the_type() {
+        let mut set = IntervalSet::new();
+        set.insert(250u8..=255);
+        set.insert(0..=3);
+        set.insert(4..=249);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=255]);
+        assert_eq!(set.len(), 256);
+
+        set.remove(0..=0);
+        set.remove(255..=255);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=254]);
+
+        let full: IntervalSet<u64> = [0..=u64::MAX].into_iter().collect();
+        assert_eq!(full.len(), u64::MAX);
+    }
+
+    /* -------------------------------------------------------------------------- */
+
+    /// The same set as a bit per value of `u8`, to check results against.
+    type Bitset = [bool; 256];
+
+    /// Result of a set operation and the operation on single bits it should match.
+    type Operation = (IntervalSet<u8>, fn(bool, bool) -> bool);
+
+    fn to_bitset(set: &IntervalSet<u8>) -> Bitset {
+        let mut bits = [false; 256];
+        for range in set.iter() {
+            for value in range {
+                bits[value as usize] = true;
+            }
+        }
+        bits
+    }
+
+    /// A small xorshift generator, the tests only need reproducible noise.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn range(&mut self) -> std::ops::RangeInclusive<u8> {
+            let start = self.next() as u8;
+            let len = (self.next() % 24) as u8;
+            start..=start.saturating_add(len)
+        }
+    }
+
+    fn random_set(rng: &mut Rng) -> (IntervalSet<u8>, Bitset) {
+        let mut set = IntervalSet::new();
+        let mut bits = [false; 256];
+
+        for _ in 0..rng.next() % 12 {
+            let range = rng.range();
+            let insert = !rng.next().is_multiple_of(3);
+            for value in range.clone() {
+                bits[value as usize] = insert;
+            }
+            if insert {
+                set.insert(range);
+            } else {
+                set.remove(range);
+            }
+        }
+
+        (set, bits)
+    }
+
+    /// Spans must be sorted, disjoint and separated by at least one value.
+    fn assert_normalized(set: &IntervalSet<u8>) {
+        let spans: Vec<_> = set.iter().collect();
+        for pair in spans.windows(2) {
+            assert!(
+                *pair[0].end() as u16 + 1 < *pair[1].start() as u16,
+                "{set:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_bitset_model() {
+        let mut rng = Rng(0x2025_1205);
+
+        for _ in 0..2000 {
+            let (a, bits_a) = random_set(&mut rng);
+            let (b, bits_b) = random_set(&mut rng);
+
+            assert_eq!(to_bitset(&a), bits_a);
+            assert_normalized(&a);
+            assert_eq!(a.len(), bits_a.iter().filter(|&&x| x).count() as u64);
+
+            let value = rng.next() as u8;
+            assert_eq!(a.contains(value), bits_a[value as usize]);
+
+            let range = rng.range();
+            assert_eq!(
+                a.contains_range(range.clone()),
+                range.clone().all(|x| bits_a[x as usize])
+            );
+
+            let operations: [Operation; 3] = [
+                (a.union(&b), |x, y| x || y),
+                (a.intersection(&b), |x, y| x && y),
+                (a.difference(&b), |x, y| x && !y),
+            ];
+
+            for (result, expected) in operations {
+                let expected: Vec<bool> =
+                    (0..256).map(|i| expected(bits_a[i], bits_b[i])).collect();
+                assert_eq!(to_bitset(&result).to_vec(), expected);
+                assert_normalized(&result);
+            }
+        }
+    }
+}
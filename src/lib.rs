@@ -1,3 +1,9 @@
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod parse;
+pub mod spatial;
 pub mod template;
+pub mod union_find;
 
 // Use this file to add helper functions and additional modules.
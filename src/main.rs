@@ -1,40 +1,106 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, check, download, examples, read, scaffold, solve, sync_examples, time, watch,
+};
+use advent_of_code::template::registry::Registry;
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::Puzzle;
 #[cfg(feature = "today")]
 use std::process;
 
+/// All solutions in `src/bin`, compiled into this binary so they can be run in-process.
+/// Not available in DHAT builds, since every solution declares its own global allocator there.
+#[cfg(not(any(test, feature = "dhat-heap")))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+/// Returns the in-process solution registry, or an empty one when running `isolated`.
+fn registry(isolated: bool) -> Registry {
+    #[cfg(not(any(test, feature = "dhat-heap")))]
+    if !isolated {
+        return Registry::new(solutions::SOLUTIONS);
+    }
+
+    let _ = isolated;
+    Registry::default()
+}
+
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::commands::time::CompareOptions;
+    use advent_of_code::template::{Day, InputSource, Puzzle, Year};
+    use std::{process, time::Duration};
+
+    /// Slowdown in percent above which `time --compare` reports a regression.
+    const DEFAULT_THRESHOLD: f64 = 10.0;
+
+    /// Time between two checks for changed files in `watch`.
+    const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_millis(500);
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: Puzzle,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
+        },
+        SyncExamples {
+            puzzle: Puzzle,
+        },
+        Examples {
+            puzzle: Puzzle,
+            list: bool,
+            blocks: Vec<usize>,
+            overwrite: bool,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
+            answer_type: Option<String>,
         },
         Solve {
-            day: Day,
+            puzzle: Puzzle,
             release: bool,
             dhat: bool,
+            alloc: bool,
             submit: Option<u8>,
+            timeout: Option<Duration>,
+            input: InputSource,
         },
         All {
+            year: Year,
+            release: bool,
+            isolated: bool,
+            parallel: Option<usize>,
+            part_timeout: Option<Duration>,
+            day_timeout: Option<Duration>,
+        },
+        Check {
+            year: Year,
             release: bool,
+            isolated: bool,
+            parallel: Option<usize>,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            warmup: Option<u32>,
+            isolated: bool,
+            part_timeout: Option<Duration>,
+            day_timeout: Option<Duration>,
+            compare: Option<CompareOptions>,
+            save_baseline: Option<String>,
+            alloc: bool,
+        },
+        Watch {
+            puzzle: Puzzle,
+            release: bool,
+            interval: Duration,
         },
         #[cfg(feature = "today")]
         Today,
@@ -43,36 +109,139 @@ mod args {
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+        let subcommand = args.subcommand()?;
+        // `--year` is accepted by every subcommand and defaults to `AOC_YEAR`.
+        let year: Option<Year> = args.opt_value_from_str("--year")?;
+        let year = year.or_else(Year::from_env);
+
+        let app_args = match subcommand.as_deref() {
+            Some("all") => {
+                let parallel = args.contains("--parallel");
+                let jobs: Option<usize> = args.opt_value_from_str("--jobs")?;
+
+                AppArguments::All {
+                    year: require_year(year)?,
+                    release: args.contains("--release"),
+                    isolated: args.contains("--isolated"),
+                    // `--jobs` implies `--parallel`, 0 threads lets rayon pick one per core.
+                    parallel: jobs.or(parallel.then_some(0)),
+                    part_timeout: parse_timeout(&mut args, "--timeout")?,
+                    day_timeout: parse_timeout(&mut args, "--day-timeout")?,
+                }
+            }
+            Some("check") => {
+                let parallel = args.contains("--parallel");
+                let jobs: Option<usize> = args.opt_value_from_str("--jobs")?;
+
+                AppArguments::Check {
+                    year: require_year(year)?,
+                    release: args.contains("--release"),
+                    isolated: args.contains("--isolated"),
+                    parallel: jobs.or(parallel.then_some(0)),
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let warmup = args.opt_value_from_str("--warmup")?;
+                let isolated = args.contains("--isolated");
+                let part_timeout = parse_timeout(&mut args, "--timeout")?;
+                let day_timeout = parse_timeout(&mut args, "--day-timeout")?;
+                let compare = args.contains("--compare");
+                let baseline: Option<String> = args.opt_value_from_str("--baseline")?;
+                let save_baseline = args.opt_value_from_str("--save-baseline")?;
+                let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+                let alloc = args.contains("--alloc");
+
+                if threshold.is_some_and(|x| !x.is_finite() || x < 0.0) {
+                    return Err("--threshold expects a non-negative percentage".into());
+                }
+
+                // `--baseline` implies `--compare`.
+                let compare = (compare || baseline.is_some()).then(|| CompareOptions {
+                    baseline,
+                    threshold: threshold.unwrap_or(DEFAULT_THRESHOLD),
+                });
+
+                let year = require_year(year)?;
+                let day: Option<Day> = args.opt_free_from_str()?;
+                if let Some(day) = day {
+                    puzzle(year, day)?;
+                }
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day,
                     store,
+                    warmup,
+                    isolated,
+                    part_timeout,
+                    day_timeout,
+                    compare,
+                    save_baseline,
+                    alloc,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: puzzle(require_year(year)?, args.free_from_str()?)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: puzzle(require_year(year)?, args.free_from_str()?)?,
+            },
+            Some("examples") => {
+                let blocks: Vec<usize> = args.values_from_str("--block")?;
+
+                if blocks.contains(&0) {
+                    return Err("--block expects a block number as shown by --list".into());
+                }
+
+                AppArguments::Examples {
+                    puzzle: puzzle(require_year(year)?, args.free_from_str()?)?,
+                    list: args.contains("--list"),
+                    blocks,
+                    overwrite: args.contains("--overwrite"),
+                }
+            }
+            Some("sync-examples") => AppArguments::SyncExamples {
+                puzzle: puzzle(require_year(year)?, args.free_from_str()?)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: puzzle(require_year(year)?, args.free_from_str()?)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
+                answer_type: args.opt_value_from_str("--answer-type")?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+            Some("solve") => {
+                let dhat = args.contains("--dhat");
+                let alloc = args.contains("--alloc");
+
+                if dhat && alloc {
+                    return Err("--alloc cannot be combined with --dhat".into());
+                }
+
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let timeout = parse_timeout(&mut args, "--timeout")?;
+                let path = args.opt_value_from_str("--input")?;
+                let example = args.contains("--example");
+                let puzzle = puzzle(require_year(year)?, args.free_from_str()?)?;
+
+                AppArguments::Solve {
+                    puzzle,
+                    release,
+                    submit,
+                    dhat,
+                    alloc,
+                    timeout,
+                    input: parse_input(path, example, args.opt_free_from_str()?)?,
+                }
+            }
+            Some("watch") => AppArguments::Watch {
+                puzzle: puzzle(require_year(year)?, args.free_from_str()?)?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                interval: parse_timeout(&mut args, "--interval")?.unwrap_or(DEFAULT_WATCH_INTERVAL),
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -93,6 +262,58 @@ mod args {
 
         Ok(app_args)
     }
+
+    fn require_year(year: Option<Year>) -> Result<Year, Box<dyn std::error::Error>> {
+        year.ok_or_else(|| "no year specified. Pass `--year <year>` or set AOC_YEAR.".into())
+    }
+
+    /// Combine year and day, checking that the event of that year has the day.
+    fn puzzle(year: Year, day: Day) -> Result<Puzzle, Box<dyn std::error::Error>> {
+        Puzzle::new(year, day).ok_or_else(|| {
+            format!(
+                "the {year} event only has {} days, there is no day {day}.",
+                year.max_day()
+            )
+            .into()
+        })
+    }
+
+    /// Combine `--input <path>`, `--example` and the free argument after the day, which is either the
+    /// number of the example or `-` for stdin, into the input source of a solution.
+    fn parse_input(
+        path: Option<String>,
+        example: bool,
+        extra: Option<String>,
+    ) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let mut source_args = vec![];
+
+        if let Some(path) = path {
+            source_args.extend(["--input".to_string(), path]);
+        }
+
+        if example {
+            source_args.push("--example".into());
+        }
+
+        source_args.extend(extra);
+
+        Ok(InputSource::from_args(&source_args)?)
+    }
+
+    /// Parse an optional timeout given in (fractional) seconds.
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+        key: &'static str,
+    ) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        let seconds: Option<f64> = args.opt_value_from_str(key)?;
+
+        match seconds {
+            Some(x) if !x.is_finite() || x <= 0.0 => {
+                Err(format!("{key} expects a positive number of seconds").into())
+            }
+            x => Ok(x.map(Duration::from_secs_f64)),
+        }
+    }
 }
 
 fn main() {
@@ -102,38 +323,106 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All {
+                year,
+                release,
+                isolated,
+                parallel,
+                part_timeout,
+                day_timeout,
+            } => all::handle(
+                year,
+                release,
+                parallel,
+                part_timeout,
+                day_timeout,
+                registry(isolated),
+            ),
+            AppArguments::Check {
+                year,
+                release,
+                isolated,
+                parallel,
+            } => check::handle(year, release, parallel, registry(isolated)),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                warmup,
+                isolated,
+                part_timeout,
+                day_timeout,
+                compare,
+                save_baseline,
+                alloc,
+            } => time::handle(
+                year,
                 day,
+                all,
+                store,
+                warmup,
+                part_timeout,
+                day_timeout,
+                compare,
+                save_baseline,
+                alloc,
+                registry(isolated),
+            ),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Examples {
+                puzzle,
+                list,
+                blocks,
+                overwrite,
+            } => examples::handle(puzzle, list, &blocks, overwrite),
+            AppArguments::SyncExamples { puzzle } => sync_examples::handle(puzzle),
+            AppArguments::Scaffold {
+                puzzle,
                 download,
                 overwrite,
+                template,
+                answer_type,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(
+                    puzzle,
+                    overwrite,
+                    template.as_deref(),
+                    answer_type.as_deref(),
+                );
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
+                    sync_examples::handle(puzzle);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
+                alloc,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                timeout,
+                input,
+            } => solve::handle(puzzle, release, dhat, alloc, submit, timeout, &input),
+            AppArguments::Watch {
+                puzzle,
+                release,
+                interval,
+            } => watch::handle(puzzle, release, interval),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match Puzzle::today() {
+                    Some(puzzle) => {
+                        scaffold::handle(puzzle, false, None, None);
+                        download::handle(puzzle);
+                        sync_examples::handle(puzzle);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 12 of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run while an event is running in \
+                            december. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,245 @@
+//! Parsing helpers that report malformed input as [`miette`] diagnostics.
+//!
+//! A [`Span`] is a piece of the puzzle input that remembers where it came from. Splitting and parsing
+//! it works like the methods of [`str`], except that failures return a [`ParseError`] pointing at the
+//! offending line and column of the input instead of panicking.
+//!
+//! ```
+//! use advent_of_code::parse::{ParseError, Span};
+//!
+//! fn parse_ranges(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+//!     Span::new(input)
+//!         .lines()
+//!         .map(|line| {
+//!             let (start, end) = line.split_once("-")?;
+//!             Ok((start.parse()?, end.parse()?))
+//!         })
+//!         .collect()
+//! }
+//! ```
+use std::{error::Error, fmt::Display, ops::Range, str::FromStr};
+
+use miette::{Diagnostic, LabeledSpan, NamedSource, SourceCode, SourceSpan};
+
+/// Name of the source shown in diagnostics.
+const SOURCE_NAME: &str = "input";
+
+/// A substring of the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// Creates a span covering the whole input.
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            text: source,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Byte offset of the span in the input.
+    pub fn offset(&self) -> usize {
+        self.text.as_ptr() as usize - self.source.as_ptr() as usize
+    }
+
+    fn sub(&self, text: &'a str) -> Self {
+        Self {
+            source: self.source,
+            text,
+        }
+    }
+
+    /// Sub-span of a byte range of this span.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        self.sub(&self.text[range])
+    }
+
+    pub fn trim(&self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> + use<'a> {
+        let span = *self;
+        self.text.lines().map(move |line| span.sub(line))
+    }
+
+    /// Splits the span into blocks separated by empty lines.
+    pub fn blocks(&self) -> impl Iterator<Item = Span<'a>> + use<'a> {
+        let span = *self;
+        self.text
+            .split("\n\n")
+            .map(move |block| span.sub(block.trim_matches('\n')))
+    }
+
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> + use<'a> {
+        let span = *self;
+        self.text.split(separator).map(move |x| span.sub(x))
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Span<'a>> + use<'a> {
+        let span = *self;
+        self.text.split_whitespace().map(move |x| span.sub(x))
+    }
+
+    /// Splits the span at the first occurrence of a separator, fails if it does not contain one.
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        self.text
+            .split_once(separator)
+            .map(|(a, b)| (self.sub(a), self.sub(b)))
+            .ok_or_else(|| self.error(format!("expected `{separator}` in `{}`", self.text)))
+    }
+
+    /// Parses the span with [`FromStr`], ignoring surrounding whitespace.
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError>
+    where
+        T::Err: Display,
+    {
+        let span = self.trim();
+        span.text.parse().map_err(|e| {
+            span.error(format!(
+                "could not parse `{}` as {}: {e}",
+                span.text,
+                short_type_name::<T>()
+            ))
+        })
+    }
+
+    /// Parses every item separated by `separator`, e.g. `1,2,3`.
+    pub fn parse_list<T: FromStr>(&self, separator: &'a str) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        self.split(separator).map(|x| x.parse()).collect()
+    }
+
+    /// Creates an error pointing at this span.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            source_code: NamedSource::new(SOURCE_NAME, self.source.to_string()),
+            span: (self.offset(), self.text.len()).into(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Span<'a> {
+    fn from(source: &'a str) -> Self {
+        Self::new(source)
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+/// Name of a type without its module path, e.g. `u64` or `Direction`.
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Malformed puzzle input, with the input attached as source code of the diagnostic.
+#[derive(Debug)]
+pub struct ParseError {
+    message: String,
+    source_code: NamedSource<String>,
+    span: SourceSpan,
+}
+
+impl ParseError {
+    /// Line and column of the start of the error, both starting at 1.
+    pub fn location(&self) -> (usize, usize) {
+        let before = &self.source_code.inner()[..self.span.offset()];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |x| x + 1) + 1;
+        (line, column)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (line, column) = self.location();
+        write!(f, "{} at line {line}, column {column}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+impl Diagnostic for ParseError {
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source_code)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(std::iter::once(LabeledSpan::new_with_span(
+            Some(self.message.clone()),
+            self.span,
+        ))))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::Span;
+
+    #[test]
+    fn parses_nested_spans() {
+        let input = "1-2\n3-4\n";
+        let ranges: Vec<(u8, u8)> = Span::new(input)
+            .lines()
+            .map(|line| {
+                let (a, b) = line.split_once("-")?;
+                Ok((a.parse()?, b.parse()?))
+            })
+            .collect::<Result<_, super::ParseError>>()
+            .unwrap();
+        assert_eq!(ranges, vec![(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn points_at_malformed_values() {
+        let input = "1,2\n3,x4\n";
+        let error = Span::new(input)
+            .lines()
+            .map(|line| line.parse_list::<u32>(","))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(error.location(), (2, 3));
+        assert_eq!(
+            error.to_string(),
+            "could not parse `x4` as u32: invalid digit found in string at line 2, column 3"
+        );
+    }
+
+    #[test]
+    fn points_at_missing_separators() {
+        let input = "10-14\n16:20";
+        let error = Span::new(input)
+            .lines()
+            .map(|line| line.split_once("-").map(|_| ()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(error.location(), (2, 1));
+    }
+
+    #[test]
+    fn splits_blocks() {
+        let input = "a\nb\n\nc\n";
+        let blocks: Vec<&str> = Span::new(input).blocks().map(|x| x.as_str()).collect();
+        assert_eq!(blocks, vec!["a\nb", "c"]);
+        assert_eq!(Span::new(input).blocks().nth(1).unwrap().offset(), 5);
+    }
+}
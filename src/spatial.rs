@@ -0,0 +1,303 @@
+//! Nearest-neighbour queries over integer points, e.g. junction boxes in space.
+//!
+//! A [`KdTree`] splits points at the median of each axis in turn. Its queries are incremental: they
+//! yield points in increasing squared distance and only search as much of the tree as the caller
+//! consumes. Distances are exact integers, ties are ordered by the index of the points.
+//!
+//! ```
+//! use advent_of_code::spatial::KdTree;
+//!
+//! let tree = KdTree::new(vec![[0, 0, 0], [10, 0, 0], [1, 1, 1], [10, 2, 0]]);
+//! let closest: Vec<_> = tree.pairs().take(2).collect();
+//! assert_eq!(closest, vec![(0, 2, 3), (1, 3, 4)]);
+//! ```
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// A point with `D` integer coordinates.
+pub type Point<const D: usize> = [i64; D];
+
+/// Squared euclidean distance between two points. Saturates at [`u128::MAX`].
+pub fn squared_distance<const D: usize>(a: &Point<D>, b: &Point<D>) -> u128 {
+    a.iter().zip(b).fold(0u128, |sum, (&a, &b)| {
+        let d = a.abs_diff(b) as u128;
+        sum.saturating_add(d * d)
+    })
+}
+
+/// A k-d tree over a fixed set of points. Points are referred to by their index in the input.
+#[derive(Clone, Debug)]
+pub struct KdTree<const D: usize> {
+    points: Vec<Point<D>>,
+    /// Indices of the points, arranged so that the median of each subtree is in the middle of its
+    /// range, with smaller coordinates on the split axis to the left of it.
+    order: Vec<usize>,
+}
+
+impl<const D: usize> KdTree<D> {
+    pub fn new(points: Vec<Point<D>>) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        Self::build(&points, &mut order, 0);
+        Self { points, order }
+    }
+
+    fn build(points: &[Point<D>], order: &mut [usize], depth: usize) {
+        if order.len() <= 1 {
+            return;
+        }
+
+        let axis = depth % D;
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by_key(mid, |&i| points[i][axis]);
+
+        let (left, right) = order.split_at_mut(mid);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &[Point<D>] {
+        &self.points
+    }
+
+    /// All points as `(index, squared distance)`, closest to `query` first.
+    pub fn nearest(&self, query: Point<D>) -> Nearest<'_, D> {
+        let mut queue = BinaryHeap::new();
+        if !self.is_empty() {
+            queue.push(Reverse((
+                0,
+                Entry::Node {
+                    range: (0, self.len()),
+                    depth: 0,
+                    bounds: ([i64::MIN; D], [i64::MAX; D]),
+                },
+            )));
+        }
+
+        Nearest {
+            tree: self,
+            query,
+            queue,
+        }
+    }
+
+    /// All pairs of distinct points as `(a, b, squared distance)` with `a < b`, closest first.
+    pub fn pairs(&self) -> Pairs<'_, D> {
+        let mut pairs = Pairs {
+            tree: self,
+            neighbours: Vec::with_capacity(self.len()),
+            queue: BinaryHeap::with_capacity(self.len()),
+        };
+
+        for a in 0..self.len() {
+            pairs.neighbours.push(self.nearest(self.points[a]));
+            pairs.advance(a);
+        }
+
+        pairs
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Pending work of a query, ordered so that subtrees are expanded before points at the same distance.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Entry<const D: usize> {
+    /// Points `order[range.0..range.1]`, which lie within `bounds` (inclusive lower and upper corner).
+    Node {
+        range: (usize, usize),
+        depth: usize,
+        bounds: (Point<D>, Point<D>),
+    },
+    Point(usize),
+}
+
+/// Squared distance from a point to the closest point of a box.
+fn distance_to_bounds<const D: usize>(
+    query: &Point<D>,
+    (lower, upper): &(Point<D>, Point<D>),
+) -> u128 {
+    let closest: Point<D> = std::array::from_fn(|axis| query[axis].clamp(lower[axis], upper[axis]));
+    squared_distance(query, &closest)
+}
+
+/// Iterator over the points of a tree by increasing distance, see [`KdTree::nearest`].
+#[derive(Clone, Debug)]
+pub struct Nearest<'a, const D: usize> {
+    tree: &'a KdTree<D>,
+    query: Point<D>,
+    queue: BinaryHeap<Reverse<(u128, Entry<D>)>>,
+}
+
+impl<const D: usize> Iterator for Nearest<'_, D> {
+    type Item = (usize, u128);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Reverse((distance, entry))) = self.queue.pop() {
+            let (range, depth, bounds) = match entry {
+                Entry::Point(i) => return Some((i, distance)),
+                Entry::Node {
+                    range,
+                    depth,
+                    bounds,
+                } => (range, depth, bounds),
+            };
+
+            let mid = (range.0 + range.1) / 2;
+            let median = self.tree.order[mid];
+            let point = self.tree.points[median];
+            let distance = squared_distance(&self.query, &point);
+            self.queue.push(Reverse((distance, Entry::Point(median))));
+
+            let axis = depth % D;
+            let (mut left, mut right) = (bounds, bounds);
+            left.1[axis] = point[axis];
+            right.0[axis] = point[axis];
+
+            for (range, bounds) in [((range.0, mid), left), ((mid + 1, range.1), right)] {
+                if range.0 < range.1 {
+                    let distance = distance_to_bounds(&self.query, &bounds);
+                    let depth = depth + 1;
+                    let node = Entry::Node {
+                        range,
+                        depth,
+                        bounds,
+                    };
+                    self.queue.push(Reverse((distance, node)));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Iterator over the pairs of points of a tree by increasing distance, see [`KdTree::pairs`].
+///
+/// Every point keeps a [`Nearest`] query that is only advanced when its closest pair is taken, so
+/// taking the first `k` pairs does not compute the distances of all pairs.
+#[derive(Clone, Debug)]
+pub struct Pairs<'a, const D: usize> {
+    tree: &'a KdTree<D>,
+    neighbours: Vec<Nearest<'a, D>>,
+    /// The next pair of each point that still has a neighbour with a larger index.
+    queue: BinaryHeap<Reverse<(u128, usize, usize)>>,
+}
+
+impl<const D: usize> Pairs<'_, D> {
+    /// Queues the next pair of point `a`, skipping neighbours with smaller indices, which report the
+    /// pair themselves.
+    fn advance(&mut self, a: usize) {
+        if let Some((b, distance)) = self.neighbours[a].by_ref().find(|&(b, _)| b > a) {
+            self.queue.push(Reverse((distance, a, b)));
+        }
+    }
+}
+
+impl<const D: usize> Iterator for Pairs<'_, D> {
+    type Item = (usize, usize, u128);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((distance, a, b)) = self.queue.pop()?;
+        self.advance(a);
+        Some((a, b, distance))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.tree.len();
+        (self.queue.len(), Some(n * n.saturating_sub(1) / 2))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{KdTree, Point, squared_distance};
+
+    /// A small xorshift generator, the tests only need reproducible noise.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// Points in a small cube, so that many distances are equal.
+        fn points<const D: usize>(&mut self, n: usize, size: u64) -> Vec<Point<D>> {
+            (0..n)
+                .map(|_| std::array::from_fn(|_| (self.next() % size) as i64 - size as i64 / 2))
+                .collect()
+        }
+    }
+
+    fn brute_force_pairs<const D: usize>(points: &[Point<D>]) -> Vec<(usize, usize, u128)> {
+        let mut pairs = vec![];
+        for a in 0..points.len() {
+            for b in a + 1..points.len() {
+                pairs.push((a, b, squared_distance(&points[a], &points[b])));
+            }
+        }
+        pairs.sort_by_key(|&(a, b, distance)| (distance, a, b));
+        pairs
+    }
+
+    #[test]
+    fn measures_exact_distances() {
+        assert_eq!(squared_distance(&[1, 2, 3], &[4, 6, 3]), 25);
+        assert_eq!(squared_distance(&[-3, 4], &[0, 0]), 25);
+        assert_eq!(
+            squared_distance(&[i64::MIN], &[i64::MAX]),
+            (u64::MAX as u128).pow(2)
+        );
+    }
+
+    #[test]
+    fn finds_nearest_points_in_order() {
+        let mut rng = Rng(0x2025_1208);
+
+        for n in [0, 1, 2, 7, 50] {
+            let points: Vec<Point<2>> = rng.points(n, 20);
+            let tree = KdTree::new(points.clone());
+            let query = [3, -4];
+
+            let mut expected: Vec<_> = (0..n)
+                .map(|i| (i, squared_distance(&query, &points[i])))
+                .collect();
+            expected.sort_by_key(|&(i, distance)| (distance, i));
+
+            assert_eq!(tree.nearest(query).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn yields_pairs_by_distance() {
+        let mut rng = Rng(0x2025_0809);
+
+        for n in [0, 1, 2, 3, 10, 80] {
+            let points: Vec<Point<3>> = rng.points(n, 16);
+            let tree = KdTree::new(points.clone());
+            assert_eq!(tree.pairs().collect::<Vec<_>>(), brute_force_pairs(&points));
+
+            let points: Vec<Point<2>> = rng.points(n, 1000);
+            let tree = KdTree::new(points.clone());
+            assert_eq!(tree.pairs().collect::<Vec<_>>(), brute_force_pairs(&points));
+        }
+    }
+
+    #[test]
+    fn handles_duplicate_points() {
+        let tree = KdTree::new(vec![[5, 5], [5, 5], [5, 5], [0, 0]]);
+        let pairs: Vec<_> = tree.pairs().take(3).collect();
+        assert_eq!(pairs, vec![(0, 1, 0), (0, 2, 0), (1, 2, 0)]);
+    }
+}
@@ -0,0 +1,276 @@
+//! Heap allocation statistics of solution parts.
+//!
+//! With the `alloc-stats` feature, the library installs a global allocator that counts every
+//! allocation, so the runner can report allocations per part. Without the feature, nothing is counted
+//! and [`measure`] returns no statistics.
+use std::collections::HashMap;
+
+use tinyjson::JsonValue;
+
+#[cfg(all(feature = "alloc-stats", feature = "dhat-heap"))]
+compile_error!("the `alloc-stats` and `dhat-heap` features both install a global allocator.");
+
+/// Heap usage of a single execution of a part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes allocated.
+    pub bytes: u64,
+    /// Maximum number of bytes in use at the same time.
+    pub peak_bytes: u64,
+}
+
+impl AllocStats {
+    /// Combine the statistics of parts that run one after another.
+    pub fn combine(self, other: Self) -> Self {
+        Self {
+            allocations: self.allocations + other.allocations,
+            bytes: self.bytes + other.bytes,
+            peak_bytes: self.peak_bytes.max(other.peak_bytes),
+        }
+    }
+}
+
+/// Run a function and count its heap allocations, if the `alloc-stats` feature is enabled.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let snapshot = counting::Snapshot::take();
+        let result = func();
+        (result, Some(snapshot.finish()))
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    (func(), None)
+}
+
+/// Human-readable size, e.g. `1.5 KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use super::AllocStats;
+
+    static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+    static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+    static CURRENT: AtomicU64 = AtomicU64::new(0);
+    static PEAK: AtomicU64 = AtomicU64::new(0);
+
+    /// Forwards to the system allocator and counts allocations.
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn grow(size: usize) {
+        let size = size as u64;
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+
+    fn count(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                count(layout.size());
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                count(layout.size());
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            shrink(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                count(new_size);
+                if new_size > layout.size() {
+                    grow(new_size - layout.size());
+                } else {
+                    shrink(layout.size() - new_size);
+                }
+            }
+            new_ptr
+        }
+    }
+
+    /// Counter values at the start of a measurement.
+    pub struct Snapshot {
+        allocations: u64,
+        allocated: u64,
+        current: u64,
+    }
+
+    impl Snapshot {
+        /// Start a measurement. Resets the peak, so only one measurement can run at a time.
+        pub fn take() -> Self {
+            let current = CURRENT.load(Ordering::Relaxed);
+            PEAK.store(current, Ordering::Relaxed);
+
+            Self {
+                allocations: ALLOCATIONS.load(Ordering::Relaxed),
+                allocated: ALLOCATED.load(Ordering::Relaxed),
+                current,
+            }
+        }
+
+        pub fn finish(self) -> AllocStats {
+            AllocStats {
+                allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+                bytes: ALLOCATED.load(Ordering::Relaxed) - self.allocated,
+                peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(self.current),
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&AllocStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+        map.insert("bytes".into(), JsonValue::Number(value.bytes as f64));
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AllocStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected alloc to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u64)
+                .ok_or(format!("Expected alloc.{key} to be a number."))
+        };
+
+        Ok(AllocStats {
+            allocations: number("allocations")?,
+            bytes: number("bytes")?,
+            peak_bytes: number("peak_bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{AllocStats, format_bytes, measure};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn combines_stats_of_parts() {
+        let a = AllocStats {
+            allocations: 2,
+            bytes: 100,
+            peak_bytes: 80,
+        };
+        let b = AllocStats {
+            allocations: 3,
+            bytes: 50,
+            peak_bytes: 50,
+        };
+        assert_eq!(
+            a.combine(b),
+            AllocStats {
+                allocations: 5,
+                bytes: 150,
+                peak_bytes: 80
+            }
+        );
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let stats = AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 1024,
+        };
+        assert_eq!(AllocStats::try_from(&JsonValue::from(&stats)), Ok(stats));
+    }
+
+    #[test]
+    fn measures_allocations() {
+        let (result, stats) = measure(|| vec![0_u8; 4096].len());
+        assert_eq!(result, 4096);
+
+        if cfg!(feature = "alloc-stats") {
+            let stats = stats.unwrap();
+            assert!(stats.allocations >= 1);
+            assert!(stats.bytes >= 4096);
+            assert!(stats.peak_bytes >= 4096);
+        } else {
+            assert_eq!(stats, None);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt::Display;
+
+use miette::{Diagnostic, Report};
+
+/// The answer of a solution part.
+///
+/// Parts can return any type that implements [`IntoAnswer`]: integers of any width, strings,
+/// an [`Answer`] or an [`Option`] of these, where `None` marks a part that is not solved yet.
+///
+/// # Display
+/// Integers and text display as is, grids display as their lines.
+///
+/// ```
+/// # use advent_of_code::template::{Answer, IntoAnswer};
+/// assert_eq!(42_u8.into_answer(), Some(Answer::Integer(42)));
+/// assert_eq!("#.\n.#".into_answer(), Some(Answer::Grid("#.\n.#".into())));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    /// A single line of text, e.g. a password or a comma-separated list.
+    Text(String),
+    /// Multiple lines, e.g. letters drawn on a grid, which have to be read by a human.
+    Grid(String),
+    /// The part has no answer, e.g. the second part of the last day of an event.
+    NotApplicable,
+}
+
+impl Answer {
+    /// Creates a [`Answer::Grid`] if the text spans multiple lines, [`Answer::Text`] otherwise.
+    pub fn from_text(text: impl Into<String>) -> Self {
+        let text = text.into();
+        if text.trim_end().contains('\n') {
+            Self::Grid(text)
+        } else {
+            Self::Text(text)
+        }
+    }
+
+    /// The answer as it is submitted to the website.
+    /// Multi-line and not applicable answers can not be submitted.
+    pub fn submission(&self) -> Result<String, &'static str> {
+        match self {
+            Self::Integer(value) => Ok(value.to_string()),
+            Self::Text(text) => Ok(text.trim().to_string()),
+            Self::Grid(_) => Err("multi-line answers have to be read and submitted by hand."),
+            Self::NotApplicable => Err("the part does not have an answer."),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Text(text) | Self::Grid(text) => f.write_str(text),
+            Self::NotApplicable => f.write_str("not applicable"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Conversion of the return value of a solution part into an [`Answer`].
+pub trait IntoAnswer {
+    /// Returns `None` if the part is not solved yet.
+    fn into_answer(self) -> Option<Answer>;
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Option<Answer> {
+        Some(self)
+    }
+}
+
+impl<T: IntoAnswer> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Option<Answer> {
+        self.and_then(IntoAnswer::into_answer)
+    }
+}
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> Option<Answer> {
+        Some(Answer::from_text(self))
+    }
+}
+
+impl IntoAnswer for &str {
+    fn into_answer(self) -> Option<Answer> {
+        Some(Answer::from_text(self))
+    }
+}
+
+impl IntoAnswer for char {
+    fn into_answer(self) -> Option<Answer> {
+        Some(Answer::Text(self.to_string()))
+    }
+}
+
+macro_rules! impl_into_answer {
+    ($($int:ty),*) => {
+        $(
+            impl IntoAnswer for $int {
+                fn into_answer(self) -> Option<Answer> {
+                    Some(Answer::Integer(i128::from(self)))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_answer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl IntoAnswer for isize {
+    fn into_answer(self) -> Option<Answer> {
+        Some(Answer::Integer(self as i128))
+    }
+}
+
+impl IntoAnswer for usize {
+    fn into_answer(self) -> Option<Answer> {
+        Some(Answer::Integer(self as i128))
+    }
+}
+
+impl IntoAnswer for u128 {
+    fn into_answer(self) -> Option<Answer> {
+        // values above `i128::MAX` keep their digits, they are submitted the same way.
+        Some(i128::try_from(self).map_or_else(|_| Answer::Text(self.to_string()), Answer::Integer))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Return value of a solution part: anything that implements [`IntoAnswer`], or a [`Result`] of it
+/// whose error is a [`Diagnostic`], e.g. a [`crate::parse::ParseError`].
+pub trait PartOutput {
+    /// Returns the answer, `None` if the part is not solved yet, or the error of the part.
+    fn into_output(self) -> Result<Option<Answer>, Report>;
+}
+
+impl<T: IntoAnswer> PartOutput for T {
+    fn into_output(self) -> Result<Option<Answer>, Report> {
+        Ok(self.into_answer())
+    }
+}
+
+impl<T: IntoAnswer, E: Diagnostic + Send + Sync + 'static> PartOutput for Result<T, E> {
+    fn into_output(self) -> Result<Option<Answer>, Report> {
+        self.map(IntoAnswer::into_answer).map_err(Report::new)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, IntoAnswer, PartOutput};
+    use crate::parse::Span;
+
+    #[test]
+    fn converts_integers_of_any_width() {
+        assert_eq!(7_u8.into_answer(), Some(Answer::Integer(7)));
+        assert_eq!((-7_i64).into_answer(), Some(Answer::Integer(-7)));
+        assert_eq!(7_usize.into_answer(), Some(Answer::Integer(7)));
+        assert_eq!(
+            u128::MAX.into_answer(),
+            Some(Answer::Text(u128::MAX.to_string()))
+        );
+    }
+
+    #[test]
+    fn converts_options() {
+        assert_eq!(Some(3_u64).into_answer(), Some(Answer::Integer(3)));
+        assert_eq!(None::<u64>.into_answer(), None);
+        assert_eq!(
+            Some(Answer::NotApplicable).into_answer(),
+            Some(Answer::NotApplicable)
+        );
+    }
+
+    #[test]
+    fn detects_multi_line_answers() {
+        assert_eq!("abc".into_answer(), Some(Answer::Text("abc".into())));
+        assert_eq!("abc\n".into_answer(), Some(Answer::Text("abc\n".into())));
+        assert_eq!(
+            String::from("#.\n.#").into_answer(),
+            Some(Answer::Grid("#.\n.#".into()))
+        );
+    }
+
+    #[test]
+    fn refuses_to_submit_multi_line_and_not_applicable_answers() {
+        assert_eq!(Answer::Integer(-12).submission(), Ok("-12".into()));
+        assert_eq!(Answer::Text(" abc ".into()).submission(), Ok("abc".into()));
+        assert!(Answer::Grid("#.\n.#".into()).submission().is_err());
+        assert!(Answer::NotApplicable.submission().is_err());
+    }
+
+    #[test]
+    fn converts_results() {
+        assert_eq!(5_u32.into_output().unwrap(), Some(Answer::Integer(5)));
+        assert_eq!(
+            Span::new("5").parse::<u32>().into_output().unwrap(),
+            Some(Answer::Integer(5))
+        );
+
+        let error = Span::new("five").parse::<u32>().into_output().unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("could not parse `five` as u32")
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Store of verified answers per year, used to check solutions for regressions.
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Puzzle, Year};
+
+static ANSWERS_FILE_NAME: &str = "answers.json";
+
+/// Represents the accepted answers for a single day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcceptedAnswer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl AcceptedAnswer {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<AcceptedAnswer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(file_path(year)) {
+            Ok(contents) => Answers::try_from(contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn get(&self, day: Day) -> Option<&AcceptedAnswer> {
+        self.data.iter().find(|answer| answer.day == day)
+    }
+
+    /// Set the accepted answer of a part, keeping the data sorted by day.
+    pub fn set(&mut self, day: Day, part: u8, value: &str) {
+        let index = match self.data.iter().position(|answer| answer.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(AcceptedAnswer {
+                    day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data
+                    .iter()
+                    .position(|answer| answer.day == day)
+                    .unwrap()
+            }
+        };
+
+        let answer = &mut self.data[index];
+        match part {
+            1 => answer.part_1 = Some(value.into()),
+            2 => answer.part_2 = Some(value.into()),
+            _ => {}
+        }
+    }
+}
+
+/// Record an answer that was accepted on submission.
+pub fn store_accepted(puzzle: Puzzle, part: u8, value: &str) -> Result<(), String> {
+    let mut answers = Answers::read_from_file(puzzle.year)?;
+    answers.set(puzzle.day, part, value);
+    answers.store_file(puzzle.year).map_err(|e| e.to_string())
+}
+
+/// Path of the file for a year, e.g. `data/2025/answers.json`.
+pub fn file_path(year: Year) -> String {
+    format!("{}/{}", year.data_dir(), ANSWERS_FILE_NAME)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(AcceptedAnswer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&AcceptedAnswer> for JsonValue {
+    fn from(value: &AcceptedAnswer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                part.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AcceptedAnswer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(AcceptedAnswer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use crate::day;
+
+    use super::Answers;
+
+    #[test]
+    fn sets_answers_in_day_order() {
+        let mut answers = Answers::default();
+        answers.set(day!(3), 2, "42");
+        answers.set(day!(1), 1, "7");
+        answers.set(day!(3), 1, "1");
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(1));
+        assert_eq!(answers.get(day!(3)).unwrap().part(1), Some("1"));
+        assert_eq!(answers.get(day!(3)).unwrap().part(2), Some("42"));
+        assert_eq!(answers.get(day!(1)).unwrap().part(2), None);
+    }
+
+    #[test]
+    fn overwrites_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "7");
+        answers.set(day!(1), 1, "8");
+        assert_eq!(answers.get(day!(1)).unwrap().part(1), Some("8"));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "7");
+        answers.set(day!(2), 2, "#.\n.#");
+
+        let json = JsonValue::from(answers.clone()).stringify().unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.data, answers.data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_json() {
+        Answers::try_from("{}".to_string()).unwrap();
+    }
+}
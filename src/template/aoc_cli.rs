@@ -1,10 +1,15 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
+    path::Path,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{
+    Puzzle,
+    aoc_client::{AocBackend, AocError, SubmitResponse},
+};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -16,7 +21,10 @@ pub enum AocCommandError {
 impl Display for AocCommandError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
+            AocCommandError::CommandNotFound => write!(
+                f,
+                "aoc-cli is not present in environment. Try running \"cargo install aoc-cli\" to install it."
+            ),
             AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
@@ -33,8 +41,8 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(puzzle: Puzzle) -> Result<Output, AocCommandError> {
+    let puzzle_path = puzzle.puzzle_path();
 
     let args = build_args(
         "read",
@@ -43,15 +51,22 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
-        day,
+        puzzle,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(puzzle: Puzzle) -> Result<Output, AocCommandError> {
+    let input_path = puzzle.data_path("inputs");
+    let puzzle_path = puzzle.puzzle_path();
+
+    // aoc-cli does not create missing folders. If this fails, aoc-cli reports that it can't write.
+    for path in [&input_path, &puzzle_path] {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).ok();
+        }
+    }
 
     let args = build_args(
         "download",
@@ -62,7 +77,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
-        day,
+        puzzle,
     );
 
     let output = call_aoc_cli(&args)?;
@@ -72,38 +87,57 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(puzzle: Puzzle, part: u8, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], puzzle);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
+    // NOTE: stdout is captured to detect whether the answer was accepted.
+    let output = Command::new("aoc")
+        .args(&args)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
+/// Backend that calls the "aoc-cli" command-line.
+pub struct AocCli;
+
+impl AocBackend for AocCli {
+    fn download(&self, puzzle: Puzzle) -> Result<(), AocError> {
+        download(puzzle)?;
+        Ok(())
     }
-}
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
-    let mut cmd_args = args.to_vec();
+    fn read(&self, puzzle: Puzzle) -> Result<(), AocError> {
+        read(puzzle)?;
+        Ok(())
+    }
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<SubmitResponse, AocError> {
+        let output = submit(puzzle, part, answer)?;
+        let message = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(SubmitResponse::from_message(message))
     }
+}
+
+fn build_args(command: &str, args: &[String], puzzle: Puzzle) -> Vec<String> {
+    let mut cmd_args = args.to_vec();
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year.to_string(),
+        "--day".into(),
+        puzzle.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
@@ -0,0 +1,530 @@
+//! Client for the Advent of Code website.
+//!
+//! Downloads, puzzle descriptions and submissions go through an [`AocBackend`]. By default this is
+//! the built-in [`HttpClient`]. Set `AOC_BACKEND=aoc-cli` to use the external `aoc` command instead.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use ureq::Agent;
+
+use crate::template::{Puzzle, aoc_cli, markdown};
+
+/// Name of the environment variable that holds the session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Name of the environment variable that overrides the website URL, e.g. to use a local stand-in server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Name of the environment variable that selects the backend.
+pub const BACKEND_ENV: &str = "AOC_BACKEND";
+/// Name of the environment variable that holds a contact sent in the User-Agent, e.g. an email address.
+pub const CONTACT_ENV: &str = "AOC_CONTACT";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The tool that sends the requests: the `repository` of `Cargo.toml`, or the crate name and version.
+const TOOL: &str = match env!("CARGO_PKG_REPOSITORY").as_bytes() {
+    [] => concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+    _ => env!("CARGO_PKG_REPOSITORY"),
+};
+
+/// User-Agent of all requests, as asked for by Advent of Code: the tool and who runs it.
+fn user_agent(contact: Option<&str>) -> String {
+    match contact.map(str::trim).filter(|x| !x.is_empty()) {
+        Some(contact) => format!("{TOOL} by {contact}"),
+        None => TOOL.to_string(),
+    }
+}
+
+#[derive(Debug)]
+pub enum AocError {
+    MissingSession,
+    Status(u16),
+    Request(String),
+    IO(io::Error),
+    Cli(aoc_cli::AocCommandError),
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::MissingSession => write!(
+                f,
+                "no session cookie found. Set {SESSION_ENV} or create the file \"~/.adventofcode.session\"."
+            ),
+            AocError::Status(404) => write!(
+                f,
+                "the puzzle was not found (HTTP 404). Is it unlocked yet?"
+            ),
+            AocError::Status(code @ (400 | 401 | 500)) => write!(
+                f,
+                "the request was rejected (HTTP {code}). Your session cookie might have expired."
+            ),
+            AocError::Status(code) => write!(f, "the request failed (HTTP {code})."),
+            AocError::Request(e) => write!(f, "the request failed: {e}"),
+            AocError::IO(e) => write!(f, "{e}"),
+            AocError::Cli(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::IO(e)
+    }
+}
+
+impl From<aoc_cli::AocCommandError> for AocError {
+    fn from(e: aoc_cli::AocCommandError) -> Self {
+        AocError::Cli(e)
+    }
+}
+
+impl From<ureq::Error> for AocError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::StatusCode(code) => AocError::Status(code),
+            e => AocError::Request(e.to_string()),
+        }
+    }
+}
+
+/// How the website responded to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    /// Answers were submitted too quickly after each other.
+    TooSoon,
+    /// The part was already solved.
+    AlreadyCompleted,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooSoon => "too soon",
+            Verdict::AlreadyCompleted => "already completed",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    /// Classify the message shown after submitting an answer.
+    pub fn from_message(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if message.contains("Did you already complete it") {
+            Verdict::AlreadyCompleted
+        } else {
+            Verdict::Unknown
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "too soon" => Ok(Verdict::TooSoon),
+            "already completed" => Ok(Verdict::AlreadyCompleted),
+            "unknown" => Ok(Verdict::Unknown),
+            x => Err(format!("Unknown verdict `{x}`.")),
+        }
+    }
+}
+
+/// Whether a wrong answer was too high or too low.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Hint {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Hint::TooHigh => "too high",
+            Hint::TooLow => "too low",
+        }
+    }
+
+    /// Extract the hint from the message shown after submitting a wrong answer.
+    pub fn from_message(message: &str) -> Option<Self> {
+        if message.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if message.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Hint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too high" => Ok(Hint::TooHigh),
+            "too low" => Ok(Hint::TooLow),
+            x => Err(format!("Unknown hint `{x}`.")),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SubmitResponse {
+    pub verdict: Verdict,
+    pub hint: Option<Hint>,
+    /// Message shown by the website.
+    pub message: String,
+}
+
+impl SubmitResponse {
+    pub fn from_message(message: String) -> Self {
+        Self {
+            verdict: Verdict::from_message(&message),
+            hint: Hint::from_message(&message),
+            message,
+        }
+    }
+}
+
+/// Access to the Advent of Code website.
+pub trait AocBackend {
+    /// Download the input and puzzle description of a puzzle to `data/<year>/`.
+    fn download(&self, puzzle: Puzzle) -> Result<(), AocError>;
+    /// Download the description of a puzzle to `data/<year>/puzzles` and print it.
+    fn read(&self, puzzle: Puzzle) -> Result<(), AocError>;
+    /// Submit the answer to a part of a puzzle.
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<SubmitResponse, AocError>;
+}
+
+/// Returns the backend selected by [`BACKEND_ENV`].
+pub fn backend() -> Result<Box<dyn AocBackend>, AocError> {
+    match env::var(BACKEND_ENV).as_deref() {
+        Ok("aoc-cli") => {
+            aoc_cli::check()?;
+            Ok(Box::new(aoc_cli::AocCli))
+        }
+        _ => Ok(Box::new(HttpClient::from_env()?)),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Built-in HTTP client for the Advent of Code website.
+pub struct HttpClient {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    user_agent: String,
+}
+
+impl HttpClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: Agent::new_with_defaults(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            user_agent: user_agent(None),
+        }
+    }
+
+    /// Add a contact, e.g. an email address, to the User-Agent of the requests.
+    pub fn with_contact(mut self, contact: &str) -> Self {
+        self.user_agent = user_agent(Some(contact));
+        self
+    }
+
+    /// Create a client from the environment. The session cookie is read from [`SESSION_ENV`] or a
+    /// `.adventofcode.session` file in the home or config directory, the contact from [`CONTACT_ENV`].
+    pub fn from_env() -> Result<Self, AocError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = read_session().ok_or(AocError::MissingSession)?;
+        let client = Self::new(&base_url, &session);
+
+        Ok(match env::var(CONTACT_ENV) {
+            Ok(contact) => client.with_contact(&contact),
+            Err(_) => client,
+        })
+    }
+
+    pub fn fetch_input(&self, puzzle: Puzzle) -> Result<String, AocError> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Fetch the description of a puzzle as Markdown.
+    pub fn fetch_puzzle(&self, puzzle: Puzzle) -> Result<String, AocError> {
+        let html = self.get(&self.day_url(puzzle))?;
+        Ok(markdown::puzzle_to_markdown(&html))
+    }
+
+    pub fn submit_answer(
+        &self,
+        puzzle: Puzzle,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitResponse, AocError> {
+        let html = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(puzzle)))
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", &self.user_agent)
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])?
+            .body_mut()
+            .read_to_string()?;
+
+        let message = markdown::puzzle_to_markdown(&html).trim().to_string();
+        Ok(SubmitResponse::from_message(message))
+    }
+
+    fn day_url(&self, puzzle: Puzzle) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocError> {
+        Ok(self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", &self.user_agent)
+            .call()?
+            .body_mut()
+            .read_to_string()?)
+    }
+}
+
+impl AocBackend for HttpClient {
+    fn download(&self, puzzle: Puzzle) -> Result<(), AocError> {
+        let input_path = puzzle.data_path("inputs");
+        let puzzle_path = puzzle.puzzle_path();
+
+        write_file(&input_path, &self.fetch_input(puzzle)?)?;
+        write_file(&puzzle_path, &self.fetch_puzzle(puzzle)?)?;
+
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(())
+    }
+
+    fn read(&self, puzzle: Puzzle) -> Result<(), AocError> {
+        let description = self.fetch_puzzle(puzzle)?;
+        write_file(&puzzle.puzzle_path(), &description)?;
+        println!("{description}");
+        Ok(())
+    }
+
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<SubmitResponse, AocError> {
+        self.submit_answer(puzzle, part, answer)
+    }
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Read the session cookie from the environment or a session file.
+fn read_session() -> Option<String> {
+    let from_env = env::var(SESSION_ENV).ok();
+
+    from_env
+        .into_iter()
+        .chain(
+            session_file_paths()
+                .into_iter()
+                .filter_map(|path| fs::read_to_string(path).ok()),
+        )
+        .map(|session| session.trim().to_string())
+        .find(|session| !session.is_empty())
+}
+
+/// Same locations as used by aoc-cli.
+fn session_file_paths() -> Vec<PathBuf> {
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok();
+
+    let config_dir = env::var("XDG_CONFIG_HOME")
+        .ok()
+        .map(PathBuf::from)
+        .or_else(|| {
+            home.as_ref()
+                .map(|home| PathBuf::from(home).join(".config"))
+        });
+
+    home.map(|home| PathBuf::from(home).join(".adventofcode.session"))
+        .into_iter()
+        .chain(config_dir.map(|dir| dir.join("adventofcode.session")))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    use super::{AocError, Hint, HttpClient, SubmitResponse, Verdict};
+    use crate::puzzle;
+
+    /// Serve a single response from a local stand-in server and capture the request it received.
+    fn serve(status: u16, body: &'static str) -> (HttpClient, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+            tx.send(request).unwrap();
+
+            let mut stream = stream;
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        });
+
+        let client = HttpClient::new(&format!("http://{address}/"), "secret");
+        (client, rx)
+    }
+
+    #[test]
+    fn fetches_input_with_session_cookie() {
+        let (client, request) = serve(200, "1\n2\n3\n");
+        assert_eq!(client.fetch_input(puzzle!(2025, 3)).unwrap(), "1\n2\n3\n");
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/3/input "));
+        assert!(request.to_lowercase().contains("cookie: session=secret"));
+    }
+
+    #[test]
+    fn identifies_tool_and_contact() {
+        let (client, request) = serve(200, "1\n");
+        let client = client.with_contact("me@example.com");
+        client.fetch_input(puzzle!(2025, 3)).unwrap();
+
+        let request = request.recv().unwrap().to_lowercase();
+        assert!(!request.contains("fspoettel"));
+        assert!(request.contains(&format!(
+            "user-agent: {} by me@example.com\r\n",
+            super::TOOL.to_lowercase()
+        )));
+        assert_eq!(super::user_agent(Some("  ")), super::TOOL);
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (client, request) = serve(
+            200,
+            r#"<html><main><article class="day-desc"><h2>--- Day 3: Test ---</h2><p>Hi.</p></article></main></html>"#,
+        );
+        assert_eq!(
+            client.fetch_puzzle(puzzle!(2025, 3)).unwrap(),
+            "## --- Day 3: Test ---\n\nHi.\n"
+        );
+        assert!(request.recv().unwrap().starts_with("GET /2025/day/3 "));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (client, request) = serve(
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let response = client.submit_answer(puzzle!(2025, 3), 2, "42").unwrap();
+        assert_eq!(response.verdict, Verdict::Correct);
+        assert!(response.message.starts_with("That's the right answer!"));
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/3/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_http_errors() {
+        let (client, _request) = serve(404, "Not found");
+        assert!(matches!(
+            client.fetch_input(puzzle!(2025, 3)),
+            Err(AocError::Status(404))
+        ));
+    }
+
+    #[test]
+    fn classifies_submit_messages() {
+        assert_eq!(
+            Verdict::from_message("That's not the right answer; your answer is too high."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::from_message("You gave an answer too recently; you have 30s left to wait."),
+            Verdict::TooSoon
+        );
+        assert_eq!(
+            Verdict::from_message(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::AlreadyCompleted
+        );
+        assert_eq!(Verdict::from_message("?"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn extracts_hints() {
+        let response = SubmitResponse::from_message(
+            "That's not the right answer; your answer is too low. If you're stuck, ...".into(),
+        );
+        assert_eq!(response.verdict, Verdict::Incorrect);
+        assert_eq!(response.hint, Some(Hint::TooLow));
+
+        let response = SubmitResponse::from_message("That's not the right answer.".into());
+        assert_eq!(response.hint, None);
+    }
+}
@@ -1,5 +1,27 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    Year, all_days,
+    registry::Registry,
+    run_multi::{MultiOptions, run_multi},
+};
+
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    parallel: Option<usize>,
+    part_timeout: Option<Duration>,
+    day_timeout: Option<Duration>,
+    registry: Registry,
+) {
+    let options = MultiOptions {
+        is_release,
+        registry,
+        parallel,
+        part_timeout,
+        day_timeout,
+        ..Default::default()
+    };
+
+    run_multi(year, &all_days(year).collect(), &options);
 }
@@ -0,0 +1,93 @@
+use std::process;
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Puzzle, Year,
+    answers::{self, Answers},
+    protocol::PartRecord,
+    registry::Registry,
+    run_multi::{MultiOptions, Outcome, run_quiet},
+};
+
+/// Run every day with stored answers against its real input and report mismatches.
+/// Exits with a non-zero status if any answer differs from the stored one.
+pub fn handle(year: Year, is_release: bool, parallel: Option<usize>, registry: Registry) {
+    let path = answers::file_path(year);
+
+    let answers = match Answers::read_from_file(year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read {path}: {e}");
+            process::exit(1);
+        }
+    };
+
+    if answers.data.is_empty() {
+        println!(
+            "No verified answers for {year} yet. Answers are stored in {path} when a submission is accepted."
+        );
+        return;
+    }
+
+    let puzzles: Vec<Puzzle> = answers
+        .data
+        .iter()
+        .filter_map(|answer| Puzzle::new(year, answer.day))
+        .collect();
+
+    let options = MultiOptions {
+        is_release,
+        registry,
+        parallel,
+        ..Default::default()
+    };
+
+    let mut correct = 0;
+    let mut mismatched = 0;
+
+    for run in run_quiet(&puzzles, &options) {
+        let answer = answers
+            .get(run.day)
+            .expect("only days with answers are run");
+
+        for part in 1..=2 {
+            let Some(expected) = answer.part(part) else {
+                continue;
+            };
+
+            let record = run.records.iter().find(|record| record.part == part);
+            let label = format!("Day {} · Part {part}:", run.day);
+
+            match record.and_then(|record| record.answer.as_deref()) {
+                Some(actual) if actual == expected => {
+                    correct += 1;
+                    println!("{label} ✔ {actual}");
+                }
+                actual => {
+                    mismatched += 1;
+                    println!(
+                        "{label} ✖ expected {expected}, got {}",
+                        describe_actual(actual, record, &run.outcome)
+                    );
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{ANSI_BOLD}Checked {} answers:{ANSI_RESET} {correct} correct, {mismatched} mismatched",
+        correct + mismatched
+    );
+
+    if mismatched > 0 {
+        process::exit(1);
+    }
+}
+
+fn describe_actual(actual: Option<&str>, record: Option<&PartRecord>, outcome: &Outcome) -> String {
+    match (actual, record, outcome) {
+        (Some(actual), _, _) => actual.into(),
+        (None, Some(record), _) => format!("nothing ({})", record.status.as_str()),
+        (None, None, Outcome::Failed(e)) => format!("nothing (failed: {e})"),
+        (None, None, _) => "nothing (part was not run)".into(),
+    }
+}
@@ -1,16 +1,17 @@
-use crate::template::{Day, aoc_cli};
+use crate::template::{Puzzle, aoc_client};
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
+pub fn handle(puzzle: Puzzle) {
+    let backend = match aoc_client::backend() {
+        Ok(backend) => backend,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = backend.download(puzzle) {
+        eprintln!("failed to download puzzle {puzzle}: {e}");
         process::exit(1);
     };
 }
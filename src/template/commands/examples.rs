@@ -0,0 +1,89 @@
+use std::{fs, process};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Puzzle,
+    examples::{CodeBlock, code_blocks, default_selection, example_path},
+};
+
+/// Number of lines shown of each block when listing them.
+const PREVIEW_LINES: usize = 3;
+
+/// Write code blocks of the downloaded puzzle description to the example files of a day.
+/// `selection` holds 1-based block numbers as shown by `list`; if empty, the examples are detected.
+pub fn handle(puzzle: Puzzle, list: bool, selection: &[usize], overwrite: bool) {
+    let puzzle_path = puzzle.puzzle_path();
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read \"{puzzle_path}\". Run `cargo download --year {} {}` first.",
+            puzzle.year, puzzle.day
+        );
+        process::exit(1);
+    };
+
+    let blocks = code_blocks(&markdown);
+
+    if blocks.is_empty() {
+        eprintln!("The puzzle description does not contain any code blocks.");
+        process::exit(1);
+    }
+
+    if list {
+        print_blocks(&blocks);
+        return;
+    }
+
+    let selection: Vec<usize> = if selection.is_empty() {
+        default_selection(&blocks)
+    } else {
+        selection.iter().map(|n| n - 1).collect()
+    };
+
+    if let Some(n) = selection.iter().find(|&&i| i >= blocks.len()) {
+        eprintln!(
+            "There is no block {}, the description has {} blocks.",
+            n + 1,
+            blocks.len()
+        );
+        process::exit(1);
+    }
+
+    for (n, &i) in selection.iter().enumerate() {
+        let path = example_path(puzzle, n + 1);
+
+        // scaffolded example files are empty and can be replaced.
+        let is_filled = fs::read_to_string(&path).is_ok_and(|x| !x.trim().is_empty());
+        if is_filled && !overwrite {
+            println!("Skipped \"{path}\", it is not empty. Pass `--overwrite` to replace it.");
+            continue;
+        }
+
+        match fs::write(&path, &blocks[i].text) {
+            Ok(()) => println!("Wrote block {} to \"{path}\"", i + 1),
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn print_blocks(blocks: &[CodeBlock]) {
+    for (i, block) in blocks.iter().enumerate() {
+        println!(
+            "{ANSI_BOLD}Block {}{ANSI_RESET} (part {}, line {}, {} lines)",
+            i + 1,
+            block.part,
+            block.line,
+            block.line_count()
+        );
+
+        for line in block.text.lines().take(PREVIEW_LINES) {
+            println!("    {line}");
+        }
+
+        if block.line_count() > PREVIEW_LINES {
+            println!("    ...");
+        }
+    }
+}
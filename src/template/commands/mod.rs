@@ -1,6 +1,10 @@
 pub mod all;
+pub mod check;
 pub mod download;
+pub mod examples;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod sync_examples;
 pub mod time;
+pub mod watch;
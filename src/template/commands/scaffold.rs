@@ -1,13 +1,14 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::{
+    Puzzle,
+    templates::{self, Placeholders},
+};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -20,6 +21,9 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +31,20 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Create the module, input and example files of a day. The module is rendered from the named
+/// template, or the default one.
+pub fn handle(puzzle: Puzzle, overwrite: bool, template: Option<&str>, answer_type: Option<&str>) {
+    let input_path = puzzle.data_path("inputs");
+    let example_path = puzzle.data_path("examples");
+    let module_path = puzzle.bin_path();
+
+    let template = match templates::load(template.unwrap_or(templates::DEFAULT_TEMPLATE)) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +54,12 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let placeholders = Placeholders::new(
+        puzzle,
+        answer_type.unwrap_or(templates::DEFAULT_ANSWER_TYPE),
+    );
+
+    match file.write_all(templates::render(&template, &placeholders).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -74,6 +89,20 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    // puzzle descriptions are written here by `download`, which does not create the folder itself.
+    let puzzles_dir = Path::new(&puzzle.puzzle_path())
+        .parent()
+        .map(Path::to_path_buf);
+    if let Some(dir) = puzzles_dir
+        && let Err(e) = fs::create_dir_all(&dir)
+    {
+        eprintln!("Failed to create puzzle folder \"{}\": {e}", dir.display());
+        process::exit(1);
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day, puzzle.year
+    );
 }
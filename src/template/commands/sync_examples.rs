@@ -0,0 +1,61 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{
+    Puzzle,
+    examples::{example_path, expected_answers, sync_tests},
+};
+
+/// Fill in the expected answers of the example tests of a solution from its puzzle description.
+pub fn handle(puzzle: Puzzle) {
+    let puzzle_path = puzzle.puzzle_path();
+    let module_path = puzzle.bin_path();
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read \"{puzzle_path}\". Run `cargo download --year {} {}` first.",
+            puzzle.year, puzzle.day
+        );
+        process::exit(1);
+    };
+
+    let Ok(module) = fs::read_to_string(&module_path) else {
+        eprintln!(
+            "Could not read \"{module_path}\". Run `cargo scaffold --year {} {}` first.",
+            puzzle.year, puzzle.day
+        );
+        process::exit(1);
+    };
+
+    let answers = expected_answers(&markdown);
+
+    if answers.iter().all(Option::is_none) {
+        println!("No expected answers found in \"{puzzle_path}\".");
+        return;
+    }
+
+    let has_part_two_example = Path::new(&example_path(puzzle, 2)).exists();
+    let (module, changed) = match sync_tests(&module, &answers, has_part_two_example) {
+        Ok(synced) => synced,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if changed.is_empty() {
+        println!("Example tests of \"{module_path}\" are up to date.");
+        return;
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("Failed to write module file: {e}");
+        process::exit(1);
+    }
+
+    for part in changed {
+        let answer = answers[usize::from(part) - 1]
+            .as_deref()
+            .unwrap_or_default();
+        println!("Updated the example test of part {part} in \"{module_path}\" to expect {answer}");
+    }
+}
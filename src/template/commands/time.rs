@@ -1,19 +1,45 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
-use crate::template::run_multi::run_multi;
+use crate::template::history::{self, History, HistoryRun};
+use crate::template::protocol::part_label;
+use crate::template::registry::Registry;
+use crate::template::run_multi::{MultiOptions, run_multi};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Options for comparing a run against the timing history.
+pub struct CompareOptions {
+    /// Name of the baseline to compare against. Compares against the previous run on this machine if not set.
+    pub baseline: Option<String>,
+    /// Slowdown in percent above which a part counts as a regression.
+    pub threshold: f64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    warmup: Option<u32>,
+    part_timeout: Option<Duration>,
+    day_timeout: Option<Duration>,
+    compare: Option<CompareOptions>,
+    save_baseline: Option<String>,
+    alloc_stats: bool,
+    registry: Registry,
+) {
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,14 +47,30 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = MultiOptions {
+        is_release: true,
+        is_timed: true,
+        warmup,
+        registry,
+        // benches run one after another so they do not compete for resources.
+        parallel: None,
+        part_timeout,
+        day_timeout,
+        alloc_stats,
+    };
+
+    let timings = run_multi(year, &days_to_run, &options).unwrap();
+
+    let run = HistoryRun::new(&timings, save_baseline);
+
+    let has_regressions = compare.is_some_and(|options| compare_to_history(year, &run, &options));
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +79,90 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if store || run.baseline.is_some() {
+        match History::append(year, &run) {
+            Ok(()) => println!("Appended run to timing history."),
+            Err(e) => eprintln!("Failed to append run to timing history: {e}"),
+        }
+    }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// Print the changes of a run against the previous run or a named baseline.
+/// Returns whether any part regressed beyond the threshold.
+fn compare_to_history(year: Year, run: &HistoryRun, options: &CompareOptions) -> bool {
+    let history = match History::read_from_file(year) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read timing history: {e}");
+            process::exit(1);
+        }
+    };
+
+    let earlier = match &options.baseline {
+        Some(name) => {
+            let Some(earlier) = history.baseline(name) else {
+                eprintln!("No baseline named \"{name}\" in the timing history.");
+                process::exit(1);
+            };
+            earlier
+        }
+        None => {
+            let Some(earlier) = history.previous(&run.machine) else {
+                println!(
+                    "\nNo previous run on \"{}\" to compare against.",
+                    run.machine
+                );
+                return false;
+            };
+            earlier
+        }
+    };
+
+    println!(
+        "\n{ANSI_BOLD}Compared to {}{ANSI_RESET} (commit {}, machine \"{}\"):",
+        earlier
+            .baseline
+            .as_ref()
+            .map_or("previous run".into(), |name| format!("baseline \"{name}\"")),
+        earlier.commit.as_deref().unwrap_or("unknown"),
+        earlier.machine
+    );
+
+    let changes = history::compare(earlier, run);
+    let mut regressions = 0;
+
+    for change in &changes {
+        let is_regression = change.is_regression(options.threshold);
+        if is_regression {
+            regressions += 1;
+        }
+
+        println!(
+            "Day {} · {}: {} → {} ({:+.1}%){}",
+            change.day,
+            part_label(change.part),
+            format_nanos(change.before),
+            format_nanos(change.after),
+            change.percent(),
+            if is_regression { " ✖ regression" } else { "" }
+        );
+    }
+
+    println!(
+        "Compared {} parts: {regressions} regressed by more than {}%.",
+        changes.len(),
+        options.threshold
+    );
+
+    regressions > 0
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
 }
@@ -0,0 +1,138 @@
+use std::{
+    process::{self, Command},
+    thread,
+    time::Duration,
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Puzzle,
+    protocol::PartRecord,
+    run_multi::{MultiOptions, child_commands},
+    watch::{Snapshot, TestSummary, diff_answers, watched_paths},
+};
+
+/// Poll the files of a day and re-run its example tests and solution whenever one of them changes.
+/// Runs until interrupted.
+pub fn handle(puzzle: Puzzle, release: bool, interval: Duration) {
+    println!(
+        "Watching {} for changes. Press Ctrl-C to stop.",
+        watched_paths(puzzle).join(", ")
+    );
+
+    let mut snapshot = None;
+    let mut previous: Option<Vec<PartRecord>> = None;
+
+    loop {
+        let current = Snapshot::take(&watched_paths(puzzle));
+
+        if let Some(earlier) = &snapshot {
+            let changed = current.changed_since(earlier);
+            if !changed.is_empty() {
+                println!("\n{ANSI_BOLD}Changed:{ANSI_RESET} {}", changed.join(", "));
+            }
+        }
+
+        if snapshot.as_ref() != Some(&current) {
+            snapshot = Some(current);
+            if let Some(records) = run_once(puzzle, release, previous.as_deref()) {
+                previous = Some(records);
+            }
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+/// Run the example tests and, if the solution compiles, the solution itself. Returns the records of
+/// the solution run.
+fn run_once(
+    puzzle: Puzzle,
+    release: bool,
+    previous: Option<&[PartRecord]>,
+) -> Option<Vec<PartRecord>> {
+    println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+    println!("------");
+
+    let tests = run_tests(puzzle, release)?;
+
+    let options = MultiOptions {
+        is_release: release,
+        ..Default::default()
+    };
+
+    let records = match child_commands::run_solution(puzzle, &options, false) {
+        Ok(child) => child.records,
+        Err(e) => {
+            eprintln!("Failed to run solution: {e:?}");
+            return None;
+        }
+    };
+
+    print_summary(&tests, previous, &records);
+    Some(records)
+}
+
+/// Run the tests of the solution bin. Prints the compiler output and returns `None` if no tests ran.
+fn run_tests(puzzle: Puzzle, release: bool) -> Option<TestSummary> {
+    let mut args = vec!["test".to_string(), "--bin".to_string(), puzzle.to_string()];
+
+    if release {
+        args.push("--release".to_string());
+    }
+
+    let output = match Command::new("cargo").args(&args).output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run `cargo test`: {e}");
+            process::exit(1);
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    match TestSummary::parse(&stdout) {
+        Some(tests) => Some(tests),
+        None => {
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("✖ Tests did not run, waiting for changes.");
+            None
+        }
+    }
+}
+
+/// Print the test results and the answers, compared to the previous run if there was one.
+fn print_summary(tests: &TestSummary, previous: Option<&[PartRecord]>, current: &[PartRecord]) {
+    println!("\n{ANSI_BOLD}Summary:{ANSI_RESET}");
+
+    if tests.is_success() {
+        println!("Tests: ✔ {} passed", tests.passed);
+    } else {
+        println!(
+            "Tests: ✖ {} passed, {} failed ({})",
+            tests.passed,
+            tests.failed,
+            tests.failures.join(", ")
+        );
+    }
+
+    for change in diff_answers(previous.unwrap_or_default(), current) {
+        let answer = |x: &Option<String>| x.clone().unwrap_or_else(|| "-".into());
+
+        if previous.is_none() {
+            println!("Part {}: {}", change.part, answer(&change.after));
+        } else if change.is_changed() {
+            println!(
+                "Part {}: {} → {ANSI_BOLD}{}{ANSI_RESET}",
+                change.part,
+                answer(&change.before),
+                answer(&change.after)
+            );
+        } else {
+            println!(
+                "Part {}: {} {ANSI_ITALIC}(unchanged){ANSI_RESET}",
+                change.part,
+                answer(&change.after)
+            );
+        }
+    }
+}
@@ -0,0 +1,468 @@
+//! Extraction of example inputs and expected answers from downloaded puzzle descriptions.
+//!
+//! Puzzle descriptions are stored as Markdown in `data/<year>/puzzles/<day>.md`, where examples are
+//! fenced code blocks. Inline code and emphasis inside of blocks is not rendered, so the contents of a
+//! block can be used as example input as is. The answer for the example is emphasized inline code,
+//! e.g. *`142`*, which is used to fill in the generated tests of a solution.
+use crate::template::Puzzle;
+
+/// A fenced code block of a puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// Part whose description contains the block.
+    pub part: u8,
+    /// Line of the opening fence in the description, starting at 1.
+    pub line: usize,
+    /// Contents of the block, ending with a line break.
+    pub text: String,
+}
+
+impl CodeBlock {
+    pub fn line_count(&self) -> usize {
+        self.text.lines().count()
+    }
+}
+
+/// Find all fenced code blocks of a puzzle description, in order.
+pub fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut part = 1;
+    let mut open: Option<CodeBlock> = None;
+
+    for (i, line) in markdown.lines().enumerate() {
+        let is_fence = line.trim_start().starts_with("```");
+
+        match &mut open {
+            Some(_) if is_fence => blocks.extend(open.take()),
+            Some(block) => {
+                block.text.push_str(line);
+                block.text.push('\n');
+            }
+            None if is_fence => {
+                open = Some(CodeBlock {
+                    part,
+                    line: i + 1,
+                    text: String::new(),
+                });
+            }
+            None if line.starts_with('#') && line.contains("Part Two") => part = 2,
+            None => {}
+        }
+    }
+
+    blocks
+}
+
+/// Index of the block that most likely holds the example input of a part: the largest block of its
+/// description, or the first one if several are equally large.
+pub fn detect_example(blocks: &[CodeBlock], part: u8) -> Option<usize> {
+    blocks
+        .iter()
+        .enumerate()
+        .filter(|(_, block)| block.part == part && !block.text.trim().is_empty())
+        .min_by_key(|(i, block)| (std::cmp::Reverse(block.line_count()), *i))
+        .map(|(i, _)| i)
+}
+
+/// The blocks written by default: the example of part one and, if the description of part two
+/// introduces a different one, the example of part two.
+pub fn default_selection(blocks: &[CodeBlock]) -> Vec<usize> {
+    let mut selection: Vec<usize> = detect_example(blocks, 1).into_iter().collect();
+
+    if let Some(i) = detect_example(blocks, 2)
+        && selection.iter().all(|&j| blocks[j].text != blocks[i].text)
+    {
+        selection.push(i);
+    }
+
+    selection
+}
+
+/// Path of the n-th example file of a puzzle, starting at 1: `01.txt`, then `01-2.txt`, `01-3.txt`...
+/// The suffixed files can be read with [`crate::template::read_file_part`].
+pub fn example_path(puzzle: Puzzle, n: usize) -> String {
+    if n <= 1 {
+        puzzle.data_path("examples")
+    } else {
+        format!("{}/examples/{}-{n}.txt", puzzle.year.data_dir(), puzzle.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Expected answer for the example of each part: the last emphasized inline code of its description.
+/// The answer of part two is `None` until its description was downloaded.
+pub fn expected_answers(markdown: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+    let mut part = 1;
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+        } else if in_block {
+            continue;
+        } else if line.starts_with('#') && line.contains("Part Two") {
+            part = 2;
+        } else if let Some(answer) = emphasized_code(line).last() {
+            answers[part - 1] = Some(answer.to_string());
+        }
+    }
+
+    answers
+}
+
+/// Inline code wrapped in emphasis, e.g. `142` in "the answer is *`142`*". Also accepts `**` and
+/// `***`, which other Markdown converters use.
+fn emphasized_code(line: &str) -> Vec<&str> {
+    let mut found = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find("*`") {
+        let code = &rest[start + 2..];
+        let Some(end) = code.find("`*") else {
+            break;
+        };
+        if !code[..end].trim().is_empty() {
+            found.push(code[..end].trim());
+        }
+        rest = &code[end + 2..];
+    }
+
+    found
+}
+
+/// Assertion of a generated test that has not been filled in yet.
+const UNFILLED_ASSERTION: &str = "assert_eq!(result, None);";
+
+/// Test function and part function of each part.
+const TESTS: [(&str, &str); 2] = [("test_part_one", "part_one"), ("test_part_two", "part_two")];
+
+/// Fill in the expected answers of the generated example tests of a solution module.
+///
+/// Only assertions that still expect `None` are replaced, so tests edited by hand are kept. A missing
+/// `test_part_two` is added once the answer of part two is known. If the example of part two has its
+/// own file, the test of part two reads it with `read_file_part`. The expected value is written as a
+/// literal of the type the part returns, unwrapping the result of parts that return a `Result`.
+/// Returns the updated module and the parts whose test changed, or an error if an answer that has to
+/// be filled in can not be written as that type.
+pub fn sync_tests(
+    module: &str,
+    answers: &[Option<String>; 2],
+    has_part_two_example: bool,
+) -> Result<(String, Vec<u8>), String> {
+    let mut module = module.to_string();
+    let mut changed = vec![];
+
+    for (i, (test, func)) in TESTS.into_iter().enumerate() {
+        let Some(answer) = &answers[i] else {
+            continue;
+        };
+
+        let Some(start) = module.find(&format!("fn {test}()")) else {
+            if let Some(end) = module.rfind('}') {
+                let assertion = expected_assertion(&module, func, answer)?;
+                let read = read_example(i == 1 && has_part_two_example);
+                module.insert_str(
+                    end,
+                    &format!(
+                        "\n    #[test]\n    fn {test}() {{\n        let result = {func}(&{read});\n        {assertion}\n    }}\n"
+                    ),
+                );
+                changed.push(i as u8 + 1);
+            }
+            continue;
+        };
+
+        let end = module[start..]
+            .find("\n    }")
+            .map_or(module.len(), |x| start + x);
+        let mut body = module[start..end].to_string();
+
+        if body.contains(UNFILLED_ASSERTION) {
+            let assertion = expected_assertion(&module, func, answer)?;
+            body = body.replace(UNFILLED_ASSERTION, &assertion);
+        }
+        if i == 1 && has_part_two_example {
+            body = body.replace(&read_example(false), &read_example(true));
+        }
+
+        if body != module[start..end] {
+            module.replace_range(start..end, &body);
+            changed.push(i as u8 + 1);
+        }
+    }
+
+    Ok((module, changed))
+}
+
+/// Expression that reads the example of a part in a generated test.
+fn read_example(is_part_two_file: bool) -> String {
+    if is_part_two_file {
+        "advent_of_code::template::read_file_part(\"examples\", PUZZLE, 2)".into()
+    } else {
+        "advent_of_code::template::read_file(\"examples\", PUZZLE)".into()
+    }
+}
+
+/// Assertion of a generated test that the part returns the expected answer, e.g.
+/// `assert_eq!(result, Some(142));` for `fn part_one(input: &str) -> Option<u64>` or
+/// `assert_eq!(result.unwrap(), 142);` for `-> Result<u64, ParseError>`.
+fn expected_assertion(module: &str, func: &str, answer: &str) -> Result<String, String> {
+    let returned = return_type(module, func)
+        .ok_or_else(|| format!("Could not find the return type of `{func}`."))?;
+
+    let (result, returned) = match generic_argument(returned, "Result") {
+        Some(ok_type) => ("result.unwrap()", ok_type),
+        None => ("result", returned),
+    };
+    let literal = match generic_argument(returned, "Option") {
+        Some(answer_type) => expected_literal(answer, answer_type).map(|x| format!("Some({x})")),
+        None => expected_literal(answer, returned),
+    }
+    .map_err(|e| format!("Could not fill in the test of `{func}`: {e}"))?;
+
+    Ok(format!("assert_eq!({result}, {literal});"))
+}
+
+/// Return type of a part, e.g. `Option<u64>` for `fn part_one(input: &str) -> Option<u64>`.
+fn return_type<'a>(module: &'a str, func: &str) -> Option<&'a str> {
+    let signature = &module[module.find(&format!("fn {func}("))?..];
+    let signature = &signature[..signature.find('{')?];
+    let (_, returned) = signature.split_once("->")?;
+    Some(returned.trim())
+}
+
+/// First generic argument of a type, e.g. `u64` for `Result<u64, ParseError>` and `Result`.
+fn generic_argument<'a>(ty: &'a str, name: &str) -> Option<&'a str> {
+    let arguments = ty
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('<')?
+        .strip_suffix('>')?;
+
+    let mut depth = 0;
+    let end = arguments
+        .char_indices()
+        .find(|&(_, c)| {
+            match c {
+                '<' | '(' => depth += 1,
+                '>' | ')' => depth -= 1,
+                _ => {}
+            }
+            c == ',' && depth == 0
+        })
+        .map_or(arguments.len(), |(i, _)| i);
+
+    Some(arguments[..end].trim())
+}
+
+/// The expected value of an assertion, as a literal of the answer type of the part.
+fn expected_literal(answer: &str, answer_type: &str) -> Result<String, String> {
+    let is_valid = match answer_type {
+        "String" => return Ok(format!("String::from({answer:?})")),
+        "&str" | "&'static str" => return Ok(format!("{answer:?}")),
+        "u8" => answer.parse::<u8>().is_ok(),
+        "u16" => answer.parse::<u16>().is_ok(),
+        "u32" => answer.parse::<u32>().is_ok(),
+        "u64" => answer.parse::<u64>().is_ok(),
+        "u128" => answer.parse::<u128>().is_ok(),
+        "usize" => answer.parse::<usize>().is_ok(),
+        "i8" => answer.parse::<i8>().is_ok(),
+        "i16" => answer.parse::<i16>().is_ok(),
+        "i32" => answer.parse::<i32>().is_ok(),
+        "i64" => answer.parse::<i64>().is_ok(),
+        "i128" => answer.parse::<i128>().is_ok(),
+        "isize" => answer.parse::<isize>().is_ok(),
+        _ => return Err(format!("answers of type `{answer_type}` are not supported")),
+    };
+
+    if is_valid {
+        Ok(answer.to_string())
+    } else {
+        Err(format!("`{answer}` is not a valid `{answer_type}`"))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        CodeBlock, code_blocks, default_selection, detect_example, example_path, expected_answers,
+        sync_tests,
+    };
+    use crate::puzzle;
+
+    const DESCRIPTION: &str = "\
+## --- Day 1: Test ---
+
+The answer for `1 2` is *`3`*. For example:
+
+```
+1 2
+3 4
+5 6
+```
+
+Or shorter:
+
+```
+1 2
+```
+
+## --- Part Two ---
+
+Now with a new example:
+
+```
+7 8
+9 10
+```
+";
+
+    #[test]
+    fn finds_code_blocks_of_both_parts() {
+        let blocks = code_blocks(DESCRIPTION);
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(
+            blocks[0],
+            CodeBlock {
+                part: 1,
+                line: 5,
+                text: "1 2\n3 4\n5 6\n".into()
+            }
+        );
+        assert_eq!(blocks[1].text, "1 2\n");
+        assert_eq!(blocks[2].part, 2);
+        assert_eq!(blocks[2].line_count(), 2);
+    }
+
+    #[test]
+    fn detects_largest_block_per_part() {
+        let blocks = code_blocks(DESCRIPTION);
+        assert_eq!(detect_example(&blocks, 1), Some(0));
+        assert_eq!(detect_example(&blocks, 2), Some(2));
+        assert_eq!(default_selection(&blocks), vec![0, 2]);
+    }
+
+    #[test]
+    fn skips_repeated_examples_of_part_two() {
+        let description = "```\na\nb\n```\n\n## --- Part Two ---\n\n```\na\nb\n```\n";
+        let blocks = code_blocks(description);
+        assert_eq!(default_selection(&blocks), vec![0]);
+        assert_eq!(
+            default_selection(&code_blocks("No examples.")),
+            Vec::<usize>::new()
+        );
+    }
+
+    #[test]
+    fn numbers_example_files() {
+        let puzzle = puzzle!(2025, 3);
+        assert_eq!(example_path(puzzle, 1), "data/2025/examples/03.txt");
+        assert_eq!(example_path(puzzle, 2), "data/2025/examples/03-2.txt");
+    }
+
+    const TEMPLATE: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+    /// The default template, scaffolded with parts that return `answer_type`.
+    fn scaffolded(answer_type: &str) -> String {
+        TEMPLATE.replace("%ANSWER_TYPE%", answer_type)
+    }
+
+    #[test]
+    fn detects_expected_answers() {
+        assert_eq!(expected_answers(DESCRIPTION), [Some("3".into()), None]);
+
+        let description = format!("{DESCRIPTION}\nThis time, the result is ***`abc`***.\n");
+        assert_eq!(
+            expected_answers(&description),
+            [Some("3".into()), Some("abc".into())]
+        );
+    }
+
+    #[test]
+    fn fills_in_generated_tests() {
+        let answers = [Some("142".into()), Some("7".into())];
+        let (module, changed) = sync_tests(&scaffolded("u64"), &answers, true).unwrap();
+
+        assert_eq!(changed, vec![1, 2]);
+        assert!(module.contains("assert_eq!(result, Some(142));"));
+        assert!(module.contains("assert_eq!(result, Some(7));"));
+        assert!(module.contains(
+            "part_two(&advent_of_code::template::read_file_part(\"examples\", PUZZLE, 2));"
+        ));
+
+        // filled in tests are left alone.
+        assert_eq!(
+            sync_tests(&module, &[Some("1".into()), None], false)
+                .unwrap()
+                .1,
+            Vec::<u8>::new()
+        );
+    }
+
+    #[test]
+    fn writes_answers_as_the_type_of_the_part() {
+        let answers = [Some("42".into()), Some("abc".into())];
+        let (module, _) = sync_tests(&scaffolded("String"), &answers, false).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(String::from(\"42\")));"));
+        assert!(module.contains("assert_eq!(result, Some(String::from(\"abc\")));"));
+
+        let (module, _) = sync_tests(&scaffolded("&'static str"), &answers, false).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(\"42\"));"));
+
+        let error = sync_tests(&scaffolded("u64"), &answers, false).unwrap_err();
+        assert!(error.contains("`abc` is not a valid `u64`"), "{error}");
+        assert!(sync_tests(&scaffolded("u8"), &[Some("300".into()), None], false).is_err());
+        assert!(sync_tests(&scaffolded("i32"), &[Some("-3".into()), None], false).is_ok());
+    }
+
+    #[test]
+    fn unwraps_results_of_parts() {
+        let module = scaffolded("u64")
+            .replacen("-> Option<u64>", "-> Result<u64, ParseError>", 1)
+            .replacen(
+                "-> Option<u64>",
+                "-> Result<Option<u64>, Box<dyn Error>>",
+                1,
+            );
+        let answers = [Some("142".into()), Some("7".into())];
+        let (module, changed) = sync_tests(&module, &answers, false).unwrap();
+
+        assert_eq!(changed, vec![1, 2]);
+        assert!(module.contains("assert_eq!(result.unwrap(), 142);"));
+        assert!(module.contains("assert_eq!(result.unwrap(), Some(7));"));
+    }
+
+    #[test]
+    fn only_reads_answer_types_of_unfilled_tests() {
+        let module = scaffolded("u64")
+            .replacen("-> Option<u64>", "-> Answer", 1)
+            .replacen(
+                "assert_eq!(result, None);",
+                "assert_eq!(result, Answer::Integer(142));",
+                1,
+            );
+        let answers = [Some("142".into()), Some("7".into())];
+        let (module, changed) = sync_tests(&module, &answers, false).unwrap();
+
+        assert_eq!(changed, vec![2]);
+        assert!(module.contains("assert_eq!(result, Some(7));"));
+
+        let error = sync_tests(&scaffolded("Answer"), &answers, false).unwrap_err();
+        assert!(error.contains("type `Answer` are not supported"), "{error}");
+    }
+
+    #[test]
+    fn adds_missing_test_of_part_two() {
+        let module = scaffolded("u64").replace("fn test_part_two", "fn other_test");
+        let (module, changed) = sync_tests(&module, &[None, Some("7".into())], false).unwrap();
+
+        assert_eq!(changed, vec![2]);
+        assert!(module.trim_end().ends_with(
+            "    #[test]\n    fn test_part_two() {\n        let result = part_two(&advent_of_code::template::read_file(\"examples\", PUZZLE));\n        assert_eq!(result, Some(7));\n    }\n}"
+        ));
+    }
+}
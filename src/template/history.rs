@@ -0,0 +1,393 @@
+//! Append-only history of benchmark runs, used to detect performance regressions.
+//!
+//! Every stored run is appended to `data/<year>/timings-history.jsonl` as a single line of JSON, so
+//! earlier runs are never rewritten. Runs can be labeled as a named baseline to compare against later.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    Day, Year,
+    protocol::PARSE_PART,
+    timings::{Timing, Timings},
+};
+
+static HISTORY_FILE_NAME: &str = "timings-history.jsonl";
+
+/// Name of the environment variable that overrides the machine id of a run.
+pub const MACHINE_ENV: &str = "AOC_MACHINE";
+
+/// Execution time of a single solved part, or of parsing for [`PARSE_PART`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    pub day: Day,
+    pub part: u8,
+    pub nanos: f64,
+}
+
+/// A single benchmark run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryRun {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Commit that was checked out, if the workspace is a git repository.
+    pub commit: Option<String>,
+    /// Machine the run was recorded on. Timings are only comparable on the same machine.
+    pub machine: String,
+    /// Name of the baseline, if the run was saved as one.
+    pub baseline: Option<String>,
+    pub parts: Vec<PartTiming>,
+}
+
+impl HistoryRun {
+    /// Capture a run of the current machine and commit from a set of timings.
+    pub fn new(timings: &Timings, baseline: Option<String>) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |x| x.as_secs()),
+            commit: git_commit(),
+            machine: machine_id(),
+            baseline,
+            parts: timings.data.iter().flat_map(part_timings).collect(),
+        }
+    }
+
+    fn get(&self, day: Day, part: u8) -> Option<f64> {
+        self.parts
+            .iter()
+            .find(|x| x.day == day && x.part == part)
+            .map(|x| x.nanos)
+    }
+}
+
+fn part_timings(timing: &Timing) -> Vec<PartTiming> {
+    [PARSE_PART, 1, 2]
+        .into_iter()
+        .filter_map(|part| {
+            Some(PartTiming {
+                day: timing.day,
+                part,
+                nanos: timing.part(part)?.solved_nanos()?,
+            })
+        })
+        .collect()
+}
+
+/// All recorded runs of a year, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub runs: Vec<HistoryRun>,
+}
+
+impl History {
+    /// Read the history of a year. If not present, returns an empty history.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(file_path(year)) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| {
+                    let json = JsonValue::from_str(line).or(Err("not valid JSON line."))?;
+                    HistoryRun::try_from(&json)
+                })
+                .collect::<Result<_, _>>()
+                .map(|runs| History { runs }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Append a run to the history of a year.
+    pub fn append(year: Year, run: &HistoryRun) -> Result<(), io::Error> {
+        let line = JsonValue::from(run)
+            .stringify()
+            .expect("run only contains serializable values");
+
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file_path(year))?;
+        writeln!(file, "{line}")
+    }
+
+    /// The most recent run recorded on a machine.
+    pub fn previous(&self, machine: &str) -> Option<&HistoryRun> {
+        self.runs.iter().rev().find(|run| run.machine == machine)
+    }
+
+    /// The most recent run saved as the named baseline.
+    pub fn baseline(&self, name: &str) -> Option<&HistoryRun> {
+        self.runs
+            .iter()
+            .rev()
+            .find(|run| run.baseline.as_deref() == Some(name))
+    }
+}
+
+/// Path of the history file for a year, e.g. `data/2025/timings-history.jsonl`.
+fn file_path(year: Year) -> String {
+    format!("{}/{}", year.data_dir(), HISTORY_FILE_NAME)
+}
+
+/// Id of the current machine, from [`MACHINE_ENV`] or the host name.
+pub fn machine_id() -> String {
+    env::var(MACHINE_ENV)
+        .or_else(|_| env::var("HOSTNAME"))
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .or_else(|_| fs::read_to_string("/etc/hostname"))
+        .map(|x| x.trim().to_string())
+        .ok()
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/// Short hash of the checked out commit, if available.
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|x| !x.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Change of the execution time of a part between two runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub day: Day,
+    pub part: u8,
+    pub before: f64,
+    pub after: f64,
+}
+
+impl Change {
+    /// Relative change in percent, positive if the part got slower.
+    pub fn percent(&self) -> f64 {
+        if self.before == 0.0 {
+            return 0.0;
+        }
+        (self.after - self.before) / self.before * 100.0
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+/// Compare the parts of a run against an earlier run. Parts missing from either run are skipped.
+pub fn compare(earlier: &HistoryRun, current: &HistoryRun) -> Vec<Change> {
+    current
+        .parts
+        .iter()
+        .filter_map(|x| {
+            Some(Change {
+                day: x.day,
+                part: x.part,
+                before: earlier.get(x.day, x.part)?,
+                after: x.nanos,
+            })
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryRun> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryRun) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("machine".into(), JsonValue::String(value.machine.clone()));
+        map.insert(
+            "baseline".into(),
+            value
+                .baseline
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "parts".into(),
+            JsonValue::Array(value.parts.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryRun {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected run to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected run.timestamp to be a number.")?;
+
+        let optional_string = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+
+        let machine = optional_string("machine").ok_or("Expected run.machine to be a string.")?;
+
+        let parts = json
+            .get("parts")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected run.parts to be an array.")?
+            .iter()
+            .map(PartTiming::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(HistoryRun {
+            timestamp,
+            commit: optional_string("commit"),
+            machine,
+            baseline: optional_string("baseline"),
+            parts,
+        })
+    }
+}
+
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected part.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected part.part to be a number.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part.nanos to be a number.")?;
+
+        Ok(PartTiming { day, part, nanos })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Change, History, HistoryRun, PartTiming, compare};
+    use crate::day;
+
+    fn run(machine: &str, baseline: Option<&str>, nanos: &[f64]) -> HistoryRun {
+        HistoryRun {
+            timestamp: 1_700_000_000,
+            commit: Some("abc1234".into()),
+            machine: machine.into(),
+            baseline: baseline.map(String::from),
+            parts: nanos
+                .iter()
+                .enumerate()
+                .map(|(i, &nanos)| PartTiming {
+                    day: day!(1),
+                    part: i as u8 + 1,
+                    nanos,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let run = run("box", Some("main"), &[100.0, 2e6]);
+        let json = JsonValue::from(&run).stringify().unwrap();
+        let parsed = HistoryRun::try_from(&json.parse::<JsonValue>().unwrap()).unwrap();
+        assert_eq!(parsed, run);
+    }
+
+    #[test]
+    fn finds_previous_runs_and_baselines() {
+        let history = History {
+            runs: vec![
+                run("box", Some("main"), &[1.0]),
+                run("laptop", None, &[2.0]),
+                run("box", None, &[3.0]),
+            ],
+        };
+        assert_eq!(history.previous("box").unwrap().parts[0].nanos, 3.0);
+        assert_eq!(history.previous("laptop").unwrap().parts[0].nanos, 2.0);
+        assert_eq!(history.previous("ci"), None);
+        assert_eq!(history.baseline("main").unwrap().parts[0].nanos, 1.0);
+        assert_eq!(history.baseline("release"), None);
+    }
+
+    #[test]
+    fn compares_common_parts() {
+        let earlier = run("box", None, &[100.0]);
+        let current = run("box", None, &[125.0, 50.0]);
+        let changes = compare(&earlier, &current);
+        assert_eq!(
+            changes,
+            vec![Change {
+                day: day!(1),
+                part: 1,
+                before: 100.0,
+                after: 125.0
+            }]
+        );
+        assert_eq!(changes[0].percent(), 25.0);
+        assert!(changes[0].is_regression(10.0));
+        assert!(!changes[0].is_regression(30.0));
+    }
+}
@@ -0,0 +1,193 @@
+//! Selection of the input a solution runs on.
+//!
+//! Solution binaries read `data/<year>/inputs/<day>.txt` unless one of these arguments is given:
+//!
+//! - `--input <path>`: a file anywhere, e.g. a colleague's input or a generated stress input.
+//! - `--example [n]`: the n-th example file of the day, `01.txt` for 1 (the default), `01-n.txt` otherwise.
+//! - `-` or `--input -`: standard input.
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    process,
+};
+
+use crate::template::{Puzzle, examples::example_path};
+
+/// Where the input of a solution is read from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in the data folder.
+    #[default]
+    Puzzle,
+    File(String),
+    /// The n-th example file, starting at 1.
+    Example(usize),
+    Stdin,
+}
+
+impl InputSource {
+    /// Read the source from command-line arguments, without the name of the program.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut sources = vec![];
+        let mut i = 0;
+
+        while i < args.len() {
+            match args[i].as_str() {
+                "--input" => {
+                    let path = args
+                        .get(i + 1)
+                        .ok_or("--input expects a path, or - for stdin")?;
+                    sources.push(if path == "-" {
+                        Self::Stdin
+                    } else {
+                        Self::File(path.clone())
+                    });
+                    i += 1;
+                }
+                "--example" => match args.get(i + 1).and_then(|x| x.parse::<usize>().ok()) {
+                    Some(0) => return Err("--example expects a number starting at 1".into()),
+                    Some(n) => {
+                        sources.push(Self::Example(n));
+                        i += 1;
+                    }
+                    None => sources.push(Self::Example(1)),
+                },
+                "-" => sources.push(Self::Stdin),
+                _ => {}
+            }
+            i += 1;
+        }
+
+        match sources.len() {
+            0 => Ok(Self::Puzzle),
+            1 => Ok(sources.remove(0)),
+            _ => Err("only one of --input, --example and - can be given".into()),
+        }
+    }
+
+    /// Arguments that select this source, to forward them to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::File(path) => vec!["--input".into(), path.clone()],
+            Self::Example(n) => vec!["--example".into(), n.to_string()],
+            Self::Stdin => vec!["-".into()],
+        }
+    }
+
+    /// Whether the source is the puzzle input, which is the only input answers can be submitted for.
+    pub fn is_puzzle_input(&self) -> bool {
+        *self == Self::Puzzle
+    }
+
+    /// Path of the file the input is read from, `None` for stdin.
+    pub fn path(&self, puzzle: Puzzle) -> Option<String> {
+        match self {
+            Self::Puzzle => Some(puzzle.data_path("inputs")),
+            Self::File(path) => Some(path.clone()),
+            Self::Example(n) => Some(example_path(puzzle, *n)),
+            Self::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, puzzle: Puzzle) -> io::Result<String> {
+        match self.path(puzzle) {
+            Some(path) => fs::read_to_string(path),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Puzzle => f.write_str("puzzle input"),
+            Self::File(path) => write!(f, "\"{path}\""),
+            Self::Example(n) => write!(f, "example {n}"),
+            Self::Stdin => f.write_str("stdin"),
+        }
+    }
+}
+
+/// The input source selected by the command-line arguments of this process. Exits if they are invalid.
+pub fn input_source() -> InputSource {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    InputSource::from_args(&args).unwrap_or_else(|e| {
+        eprintln!("Unexpected command-line input: {e}");
+        process::exit(1);
+    })
+}
+
+/// Read the input of a solution binary as selected by its command-line arguments.
+/// Exits with an error message if the input can not be read.
+pub fn read_input(puzzle: Puzzle) -> String {
+    let source = input_source();
+
+    source.read(puzzle).unwrap_or_else(|e| {
+        match (&source, source.path(puzzle)) {
+            (InputSource::Puzzle | InputSource::Example(_), Some(path)) => {
+                eprintln!("Could not read {source} from \"{path}\": {e}");
+            }
+            _ => eprintln!("Could not read {source}: {e}"),
+        }
+        process::exit(1);
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    fn parse(args: &str) -> Result<InputSource, String> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        InputSource::from_args(&args)
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse("--time"), Ok(InputSource::Puzzle));
+        assert_eq!(
+            parse("--input other.txt --time"),
+            Ok(InputSource::File("other.txt".into()))
+        );
+        assert_eq!(parse("--input -"), Ok(InputSource::Stdin));
+        assert_eq!(parse("--timeout 5 -"), Ok(InputSource::Stdin));
+        assert_eq!(parse("--example"), Ok(InputSource::Example(1)));
+        assert_eq!(parse("--example --time"), Ok(InputSource::Example(1)));
+        assert_eq!(parse("--example 2"), Ok(InputSource::Example(2)));
+    }
+
+    #[test]
+    fn rejects_invalid_sources() {
+        assert!(parse("--input").is_err());
+        assert!(parse("--example 0").is_err());
+        assert!(parse("--example -").is_err());
+    }
+
+    #[test]
+    fn round_trips_arguments() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::File("a.txt".into()),
+            InputSource::Example(3),
+            InputSource::Stdin,
+        ] {
+            assert_eq!(
+                InputSource::from_args(&source.to_args()),
+                Ok(source.clone())
+            );
+        }
+        assert!(!InputSource::Stdin.is_puzzle_input());
+        assert_eq!(
+            InputSource::Example(2).path(crate::puzzle!(2025, 1)),
+            Some("data/2025/examples/01-2.txt".into())
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! Minimal conversion of Advent of Code puzzle pages to Markdown.
+//!
+//! Puzzle descriptions only use a small, well-formed subset of HTML, so this handles the tags found
+//! in `<article class="day-desc">` elements and ignores everything else.
+
+/// Convert the puzzle descriptions of a puzzle page to Markdown.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let articles: Vec<String> = html
+        .split("<article")
+        .skip(1)
+        .filter_map(|article| {
+            let start = article.find('>')? + 1;
+            let end = article.find("</article>").unwrap_or(article.len());
+            Some(html_to_markdown(&article[start..end]))
+        })
+        .collect();
+
+    let mut markdown = articles.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// Convert a fragment of puzzle HTML to Markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    // Inline code is buffered, so emphasis inside of it can be moved outside of the backticks.
+    let mut code: Option<(String, bool)> = None;
+    let mut links: Vec<String> = vec![];
+
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(start) => {
+                let end = rest[start..]
+                    .find('>')
+                    .map_or(rest.len(), |x| start + x + 1);
+                let tag = &rest[start..end];
+                let text = &rest[..start];
+                rest = &rest[end..];
+                (text, Some(tag))
+            }
+            None => {
+                let text = rest;
+                rest = "";
+                (text, None)
+            }
+        };
+
+        let text = decode_entities(text);
+
+        match &mut code {
+            Some((buffer, _)) => buffer.push_str(&text),
+            None if in_pre => out.push_str(&text),
+            None => {
+                let text = text.replace('\n', " ");
+                // NOTE: drop whitespace between block elements.
+                if out.is_empty() || out.ends_with('\n') {
+                    out.push_str(text.trim_start());
+                } else {
+                    out.push_str(&text);
+                }
+            }
+        }
+
+        let Some(tag) = tag else {
+            break;
+        };
+
+        let (name, is_closing) = parse_tag(tag);
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", false) if !in_pre => code = Some((String::new(), false)),
+            ("code", true) if !in_pre => {
+                if let Some((buffer, emphasized)) = code.take() {
+                    if emphasized {
+                        out.push_str(&format!("*`{buffer}`*"));
+                    } else {
+                        out.push_str(&format!("`{buffer}`"));
+                    }
+                }
+            }
+            ("em", false) if !in_pre => match &mut code {
+                Some((_, emphasized)) => *emphasized = true,
+                None => out.push('*'),
+            },
+            ("em", true) if !in_pre && code.is_none() => out.push('*'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            ("br", _) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    out.trim().to_string()
+}
+
+/// Returns the lowercase name of a tag and whether it is a closing tag.
+fn parse_tag(tag: &str) -> (String, bool) {
+    let inner = tag.trim_start_matches('<').trim_end_matches('>');
+    let is_closing = inner.starts_with('/');
+    let name = inner
+        .trim_start_matches('/')
+        .split(|c: char| c.is_whitespace() || c == '/')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    (name, is_closing)
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{html_to_markdown, puzzle_to_markdown};
+
+    #[test]
+    fn converts_headings_and_paragraphs() {
+        let html = "<h2>--- Day 1: Test ---</h2><p>Hello,\nworld.</p><p>Bye.</p>";
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1: Test ---\n\nHello, world.\n\nBye."
+        );
+    }
+
+    #[test]
+    fn converts_code_blocks() {
+        let html =
+            "<p>Example:</p>\n<pre><code>1 &lt; 2\n<em>3</em> &amp; 4\n</code></pre>\n<p>Done.</p>";
+        assert_eq!(
+            html_to_markdown(html),
+            "Example:\n\n```\n1 < 2\n3 & 4\n```\n\nDone."
+        );
+    }
+
+    #[test]
+    fn converts_inline_formatting() {
+        let html = r#"<p>A <em>b</em> <code>c</code> with <a href="/2025/about">link</a>.</p>"#;
+        assert_eq!(
+            html_to_markdown(html),
+            "A *b* `c` with [link](/2025/about)."
+        );
+    }
+
+    #[test]
+    fn moves_emphasis_out_of_code() {
+        let html = "<p>The answer is <code><em>142</em></code>.</p>";
+        assert_eq!(html_to_markdown(html), "The answer is *`142`*.");
+    }
+
+    #[test]
+    fn converts_lists() {
+        let html = "<ul>\n<li>One</li>\n<li>Two</li>\n</ul>";
+        assert_eq!(html_to_markdown(html), "- One\n- Two");
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = r#"<main><article class="day-desc"><h2>--- Day 1 ---</h2><p>One.</p></article>
+<p>Your puzzle answer was <code>1</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Two.</p></article></main>"#;
+        assert_eq!(
+            puzzle_to_markdown(html),
+            "## --- Day 1 ---\n\nOne.\n\n## --- Part Two ---\n\nTwo.\n"
+        );
+    }
+}
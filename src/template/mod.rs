@@ -1,68 +1,178 @@
 use std::{env, fs};
 
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod protocol;
+pub mod registry;
 pub mod runner;
 
+pub use answer::*;
 pub use day::*;
+pub use input::*;
+pub use puzzle::*;
+pub use year::*;
 
+mod alloc;
+mod answer;
+mod answers;
 mod day;
+mod examples;
+mod history;
+mod input;
+mod markdown;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod stats;
+mod submissions;
+mod templates;
 mod timings;
+mod watch;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a puzzle to a string, e.g. `data/2025/inputs/01.txt`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(puzzle.data_path(folder));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(puzzle.year.data_dir())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// With `parse`, the solution declares a `fn parse(input: &str) -> T`, or a `Result<T, E>` whose
+/// error is a [`miette::Diagnostic`] such as [`crate::parse::ParseError`]. The input is parsed once
+/// and both parts take a `&T` instead of the input. Parsing is timed separately from the parts and
+/// reported like a failing part if it returns an error, e.g. `solution!(2025, 1, parse)` or
+/// `solution!(2025, 1, parse, 1)`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]);
+    };
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1]);
+    };
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2]);
+    };
+    ($year:expr, $day:expr, parse) => {
+        $crate::solution!(@parse $year, $day, [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, parse, 1) => {
+        $crate::solution!(@parse $year, $day, [part_one, 1]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr, parse, 2) => {
+        $crate::solution!(@parse $year, $day, [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@constants $year, $day);
+
+        fn main() {
+            use $crate::template::runner::*;
+            let input = $crate::template::read_input(PUZZLE);
+            $( run_part($func, &input, PUZZLE, $part); )*
+        }
+
+        /// Registry entry for this solution, used to run it in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                puzzle: PUZZLE,
+                parts: &[$(
+                    $crate::template::registry::Part {
+                        part: $part,
+                        solve: |input| $crate::template::PartOutput::into_output($func(input)).ok().flatten().map(|x| x.to_string()),
+                    },
+                )*],
+                run: |input, options| {
+                    vec![$(
+                        $crate::template::runner::execute_part($func, input, PUZZLE, $part, options).1,
+                    )*]
+                },
+            };
+    };
+
+    (@parse $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@constants $year, $day);
+
+        fn main() {
+            use $crate::template::runner::*;
+            let input = $crate::template::read_input(PUZZLE);
+            let Some(parsed) = run_parse(|input| $crate::solution!(@parse_input input), &input, PUZZLE) else {
+                return;
+            };
+            $( run_part($func, &parsed, PUZZLE, $part); )*
+        }
+
+        /// Registry entry for this solution, used to run it in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                puzzle: PUZZLE,
+                parts: &[$(
+                    $crate::template::registry::Part {
+                        part: $part,
+                        solve: |input| {
+                            let parsed = $crate::solution!(@parse_input input).ok()?;
+                            $crate::template::PartOutput::into_output($func(&parsed)).ok().flatten().map(|x| x.to_string())
+                        },
+                    },
+                )*],
+                run: |input, options| {
+                    use $crate::template::runner::*;
+                    let (parsed, record) = execute_parse(|input| $crate::solution!(@parse_input input), input, PUZZLE, options);
+                    let Some(parsed) = parsed else {
+                        return vec![record];
+                    };
+                    vec![record, $( execute_part($func, &parsed, PUZZLE, $part, options).1, )*]
+                },
+            };
+    };
+
+    // Calls `parse` and converts its return value to a `Result`, see `runner::FallibleParse`.
+    (@parse_input $input:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::template::runner::{FallibleParse as _, InfallibleParse as _};
+        let parsed = parse($input);
+        (&parsed).parse_kind().into_result(parsed)
+    }};
+
+    (@constants $year:expr, $day:expr) => {
+        /// The year of the event.
+        #[allow(dead_code)]
+        const YEAR: $crate::template::Year = $crate::year!($year);
+
         /// The current day.
+        #[allow(dead_code)]
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The current puzzle.
+        const PUZZLE: $crate::template::Puzzle = $crate::puzzle!($year, $day);
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
-
-        fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
-        }
     };
 }
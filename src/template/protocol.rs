@@ -0,0 +1,359 @@
+//! Machine-readable result records exchanged between solution binaries and `run_multi`.
+//!
+//! When the environment variable [`RESULTS_FILE_ENV`] is set, `run_part` appends one JSON object
+//! per solution part to the file at that path. Each record is written as a single line, so the file
+//! can be consumed as JSON lines independently of whatever the solution prints to stdout.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, alloc::AllocStats, stats::Stats};
+
+/// Name of the environment variable that holds the path of the results file.
+pub const RESULTS_FILE_ENV: &str = "AOC_RESULTS_FILE";
+
+/// Part number of records that time the `parse` function of a solution.
+pub const PARSE_PART: u8 = 0;
+
+/// Name of a part in output, e.g. `Part 1`, or `Parse` for [`PARSE_PART`].
+pub fn part_label(part: u8) -> String {
+    if part == PARSE_PART {
+        "Parse".into()
+    } else {
+        format!("Part {part}")
+    }
+}
+
+/// Outcome of running a single solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+    /// The part panicked.
+    Panicked,
+    /// The part did not finish within its timeout.
+    TimedOut,
+    /// The part returned [`crate::template::Answer::NotApplicable`].
+    NotApplicable,
+    /// The part returned an error.
+    Errored,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed out",
+            Status::NotApplicable => "not applicable",
+            Status::Errored => "errored",
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            "panicked" => Ok(Status::Panicked),
+            "timed out" => Ok(Status::TimedOut),
+            "not applicable" => Ok(Status::NotApplicable),
+            "errored" => Ok(Status::Errored),
+            x => Err(format!("Unknown record status `{x}`.")),
+        }
+    }
+}
+
+/// Structured result of a single solution part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub day: Day,
+    /// Number of the part, or [`PARSE_PART`] for the `parse` function. A parse record has no answer and
+    /// is [`Status::Solved`] once the input was parsed.
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// Panic message or other details explaining the status.
+    pub message: Option<String>,
+    /// Execution time in nanoseconds. When benched, this is the mean of all samples.
+    pub nanos: f64,
+    pub samples: usize,
+    /// Statistics over all samples, if the part was benched.
+    pub stats: Option<Stats>,
+    /// Heap usage of the first execution, if allocations are counted.
+    pub alloc: Option<AllocStats>,
+}
+
+impl PartRecord {
+    /// Serialize the record as a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        // `stringify` escapes line breaks, so multi-line answers stay on one line.
+        JsonValue::from(self)
+            .stringify()
+            .expect("record only contains serializable values")
+    }
+
+    /// Append the record to the results file if [`RESULTS_FILE_ENV`] is set.
+    pub fn emit(&self) -> io::Result<()> {
+        let Ok(path) = std::env::var(RESULTS_FILE_ENV) else {
+            return Ok(());
+        };
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", self.to_json_line())
+    }
+}
+
+/// Read all records from a results file. A missing file yields no records.
+pub fn read_records(path: &Path) -> Result<Vec<PartRecord>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(PartRecord::from_str)
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "message".into(),
+            value
+                .message
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "alloc".into(),
+            value
+                .alloc
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for PartRecord {
+    type Err = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("Record is not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected record.part to be a number.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let message = json.get("message").and_then(|v| v.get::<String>()).cloned();
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as usize)
+            .ok_or("Expected record.samples to be a number.")?;
+
+        let stats = json
+            .get("stats")
+            .filter(|v| !v.is_null())
+            .map(Stats::try_from)
+            .transpose()?;
+
+        let alloc = json
+            .get("alloc")
+            .filter(|v| !v.is_null())
+            .map(AllocStats::try_from)
+            .transpose()?;
+
+        Ok(PartRecord {
+            day,
+            part,
+            status,
+            answer: answer.cloned(),
+            message,
+            nanos,
+            samples,
+            stats,
+            alloc,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use super::{AllocStats, PartRecord, Stats, Status};
+    use crate::day;
+
+    fn get_mock_record(answer: Option<&str>) -> PartRecord {
+        PartRecord {
+            day: day!(3),
+            part: 2,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            answer: answer.map(String::from),
+            message: None,
+            nanos: 74130.0,
+            samples: 10000,
+            stats: None,
+            alloc: None,
+        }
+    }
+
+    #[test]
+    fn round_trips_records() {
+        let record = get_mock_record(Some("42"));
+        let parsed = PartRecord::from_str(&record.to_json_line()).unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn round_trips_unsolved_records() {
+        let record = get_mock_record(None);
+        let parsed = PartRecord::from_str(&record.to_json_line()).unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn handles_answers_with_decorations() {
+        let record = get_mock_record(Some("@ ( ) ms (2s @ 5 samples)"));
+        let parsed = PartRecord::from_str(&record.to_json_line()).unwrap();
+        assert_eq!(parsed.answer.unwrap(), "@ ( ) ms (2s @ 5 samples)");
+    }
+
+    #[test]
+    fn keeps_multi_line_answers_on_one_line() {
+        let record = get_mock_record(Some("#..\n.#.\n..#"));
+        let line = record.to_json_line();
+        assert_eq!(line.lines().count(), 1);
+        assert_eq!(PartRecord::from_str(&line).unwrap(), record);
+    }
+
+    #[test]
+    fn round_trips_panicked_records() {
+        let record = PartRecord {
+            status: Status::Panicked,
+            message: Some("index out of bounds".into()),
+            ..get_mock_record(None)
+        };
+        let parsed = PartRecord::from_str(&record.to_json_line()).unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn round_trips_not_applicable_records() {
+        let record = PartRecord {
+            status: Status::NotApplicable,
+            ..get_mock_record(None)
+        };
+        let parsed = PartRecord::from_str(&record.to_json_line()).unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn round_trips_benched_records() {
+        let samples: Vec<Duration> = (1..=20).map(Duration::from_nanos).collect();
+        let record = PartRecord {
+            stats: Stats::from_samples(&samples),
+            alloc: Some(AllocStats {
+                allocations: 3,
+                bytes: 2048,
+                peak_bytes: 1024,
+            }),
+            ..get_mock_record(Some("42"))
+        };
+        let parsed = PartRecord::from_str(&record.to_json_line()).unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn handles_records_without_message() {
+        let json = r#"{ "day": "01", "part": 1, "status": "timed out", "answer": null, "nanos": 0, "samples": 1 }"#;
+        let record = PartRecord::from_str(json).unwrap();
+        assert_eq!(record.status, Status::TimedOut);
+        assert_eq!(record.message, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_records() {
+        PartRecord::from_str(r#"{ "day": "01", "part": 1 }"#).unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{Day, Year};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Identifies a puzzle by the year of its event and its day.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of its solution binary.
+///
+/// ```
+/// # use advent_of_code::{Day, Puzzle, Year};
+/// let puzzle = Puzzle::new(Year::new(2024).unwrap(), Day::new(8).unwrap()).unwrap();
+/// assert_eq!(puzzle.to_string(), "2024-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    /// Creates a [`Puzzle`] if the day is part of the event of that year,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: Year, day: Day) -> Option<Self> {
+        if day.into_inner() > year.max_day() {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    /// Path of the solution module, e.g. `src/bin/2025-01.rs`.
+    pub fn bin_path(self) -> String {
+        format!("src/bin/{self}.rs")
+    }
+
+    /// Path of a text file of this puzzle in a data folder, e.g. `data/2025/inputs/01.txt`.
+    pub fn data_path(self, folder: &str) -> String {
+        format!("{}/{folder}/{}.txt", self.year.data_dir(), self.day)
+    }
+
+    /// Path of the puzzle description, e.g. `data/2025/puzzles/01.md`.
+    pub fn puzzle_path(self) -> String {
+        format!("{}/puzzles/{}.md", self.year.data_dir(), self.day)
+    }
+}
+
+#[cfg(feature = "today")]
+impl Puzzle {
+    /// Returns the puzzle of the current day if an event is running, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() != 12 {
+            return None;
+        }
+
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        Self::new(year, Day::new(u8::try_from(today.day()).ok()?)?)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Puzzle`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {
+        const {
+            $crate::template::Puzzle::new($crate::year!($year), $crate::day!($day))
+                .expect("invalid puzzle, the event of that year does not have this day")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Puzzle;
+    use crate::{day, year};
+
+    #[test]
+    fn validates_days_of_year() {
+        assert!(Puzzle::new(year!(2024), day!(25)).is_some());
+        assert!(Puzzle::new(year!(2025), day!(12)).is_some());
+        assert_eq!(Puzzle::new(year!(2025), day!(13)), None);
+    }
+
+    #[test]
+    fn namespaces_paths_by_year() {
+        let puzzle = puzzle!(2024, 3);
+        assert_eq!(puzzle.to_string(), "2024-03");
+        assert_eq!(puzzle.bin_path(), "src/bin/2024-03.rs");
+        assert_eq!(puzzle.data_path("inputs"), "data/2024/inputs/03.txt");
+        assert_eq!(puzzle.puzzle_path(), "data/2024/puzzles/03.md");
+    }
+}
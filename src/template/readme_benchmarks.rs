@@ -1,9 +1,11 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fs, io, time::Duration};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::alloc::{AllocStats, format_bytes};
+use crate::template::protocol::Status;
+use crate::template::timings::{PartTiming, Timings};
+use crate::template::{Puzzle, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -26,8 +28,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: Puzzle) -> String {
+    format!("./{}", puzzle.bin_path())
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -52,8 +54,12 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(prefix: &str, year: Year, timings: Timings, total_millis: f64) -> String {
+    let header = format!("{prefix} Benchmarks ({year})");
+    // heap columns are only shown once allocations were counted for any day.
+    let has_alloc = timings.data.iter().any(|timing| timing.alloc().is_some());
+    // the parse column is only shown once any day parses its input separately.
+    let has_parse = timings.data.iter().any(|timing| timing.parse.is_some());
 
     let mut lines: Vec<String> = vec![
         MARKER.into(),
@@ -63,15 +69,38 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
         "| :---: | :---: | :---:  |".into(),
     ];
 
+    if has_parse {
+        lines[3] = "| Day | Parse | Part 1 | Part 2 |".into();
+        lines[4] = "| :---: | :---: | :---: | :---:  |".into();
+    }
+
+    if has_alloc {
+        lines[3].push_str(" Allocations | Allocated | Peak heap |");
+        lines[4].push_str(" :---: | :---: | :---: |");
+    }
+
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+        let Some(puzzle) = Puzzle::new(year, timing.day) else {
+            continue;
+        };
+        let path = get_path_for_bin(puzzle);
+        let mut line = format!("| [Day {}]({}) |", timing.day.into_inner(), path);
+
+        if has_parse {
+            line.push_str(&format!(" `{}` |", format_part(timing.parse.as_ref())));
+        }
+
+        line.push_str(&format!(
+            " `{}` | `{}` |",
+            format_part(timing.part_1.as_ref()),
+            format_part(timing.part_2.as_ref())
         ));
+
+        if has_alloc {
+            line.push_str(&format_alloc(timing.alloc()));
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -81,18 +110,53 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+/// Show the timing of a part in human units, or why there is none if it did not return an answer.
+fn format_part(timing: Option<&PartTiming>) -> String {
+    match timing {
+        Some(PartTiming {
+            status: Status::Solved,
+            nanos,
+            ..
+        }) => {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let duration = Duration::from_nanos(*nanos as u64);
+            format!("{duration:.1?}")
+        }
+        Some(timing) => timing.status.as_str().into(),
+        None => "-".into(),
+    }
+}
+
+/// Heap columns of a day, allocations of both parts are combined.
+fn format_alloc(alloc: Option<AllocStats>) -> String {
+    match alloc {
+        Some(alloc) => format!(
+            " {} | {} | {} |",
+            alloc.allocations,
+            format_bytes(alloc.bytes),
+            format_bytes(alloc.peak_bytes)
+        ),
+        None => " - | - | - |".into(),
+    }
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -100,28 +164,44 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::alloc::AllocStats,
+        template::protocol::Status,
+        template::timings::{PartTiming, Timing, Timings},
+        year,
+    };
+
+    fn solved(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            status: Status::Solved,
+            nanos: millis * 1e6,
+            samples: 100,
+            stats: None,
+            alloc: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    parse: None,
+                    part_1: solved(10.0),
+                    part_2: solved(20.0),
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    parse: None,
+                    part_1: solved(30.0),
+                    part_2: solved(40.0),
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    parse: None,
+                    part_1: solved(40.0),
+                    part_2: solved(50.0),
                 },
             ],
         }
@@ -131,47 +211,93 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn formats_failed_parts() {
+        let mut timings = get_mock_timings();
+        timings.data[2].part_2 = Some(PartTiming {
+            status: Status::TimedOut,
+            ..solved(5000.0).unwrap()
+        });
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, year!(2025), timings, 190.0).unwrap();
+        assert!(s.contains("| [Day 4](./src/bin/2025-04.rs) | `40.0ms` | `timed out` |"));
+    }
+
+    #[test]
+    fn formats_parse_column() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = solved(5.0);
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, year!(2025), timings, 195.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2025-01.rs) | `-` | `10.0ms` | `20.0ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2025-02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |"));
+    }
+
+    #[test]
+    fn formats_allocation_columns() {
+        let mut timings = get_mock_timings();
+        let day_1 = &mut timings.data[0];
+        for (part, peak_bytes) in [(&mut day_1.part_1, 512), (&mut day_1.part_2, 4096)] {
+            part.as_mut().unwrap().alloc = Some(AllocStats {
+                allocations: 2,
+                bytes: 4096,
+                peak_bytes,
+            });
+        }
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, year!(2025), timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Allocations | Allocated | Peak heap |"));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2025-01.rs) | `10.0ms` | `20.0ms` | 4 | 8.0 KiB | 4.0 KiB |"
+        ));
+        assert!(s.contains("| [Day 2](./src/bin/2025-02.rs) | `30.0ms` | `40.0ms` | - | - | - |"));
+    }
+
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "## Benchmarks (2025)",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2025-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2025-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2025-04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
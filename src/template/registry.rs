@@ -0,0 +1,249 @@
+//! In-process registry of solutions.
+//!
+//! Every `solution!` invocation defines a `SOLUTION` constant describing its parts. The main binary
+//! compiles all solutions from `src/bin` into a module generated by `build.rs` and collects these
+//! constants into a [`Registry`], which allows `all` and `time` to run every day in a single process.
+use crate::template::{Puzzle, protocol::PartRecord, runner::RunOptions};
+
+/// A single part of a solution.
+#[derive(Clone, Copy)]
+pub struct Part {
+    pub part: u8,
+    /// Calls the part function and converts its answer to a string. Parses the input first if the
+    /// solution has a `parse` function. Parts that return an error are treated as unsolved.
+    pub solve: fn(&str) -> Option<String>,
+}
+
+/// The parts of a solution for a puzzle.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: Puzzle,
+    pub parts: &'static [Part],
+    /// Executes all parts through the runner, printing and optionally benching them. If the solution
+    /// has a `parse` function, the input is parsed once and the first record times parsing.
+    pub run: fn(&str, &RunOptions) -> Vec<PartRecord>,
+}
+
+/// A set of solutions that can be called in-process.
+#[derive(Clone, Copy, Default)]
+pub struct Registry {
+    solutions: &'static [Solution],
+}
+
+impl Registry {
+    pub const fn new(solutions: &'static [Solution]) -> Self {
+        Self { solutions }
+    }
+
+    /// Returns the solution for a puzzle if it is registered.
+    pub fn get(&self, puzzle: Puzzle) -> Option<&'static Solution> {
+        self.solutions
+            .iter()
+            .find(|solution| solution.puzzle == puzzle)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Part, Registry, Solution};
+    use crate::{
+        parse::{ParseError, Span},
+        puzzle,
+        template::Answer,
+        template::protocol::{PARSE_PART, Status},
+        template::runner::{RunOptions, execute_parse, execute_part},
+    };
+
+    fn part_one(input: &str) -> Option<u64> {
+        Some(input.len() as u64)
+    }
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        Span::new(input).parse_list(",")
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn sum(numbers: &Vec<u64>) -> Option<u64> {
+        Some(numbers.iter().sum())
+    }
+
+    static SOLUTIONS: &[Solution] = &[
+        Solution {
+            puzzle: puzzle!(2025, 3),
+            parts: &[Part {
+                part: 1,
+                solve: |input| part_one(input).map(|x| x.to_string()),
+            }],
+            run: |input, options| {
+                vec![execute_part(part_one, input, puzzle!(2025, 3), 1, options).1]
+            },
+        },
+        Solution {
+            puzzle: puzzle!(2025, 4),
+            parts: &[Part {
+                part: 1,
+                solve: |input| sum(&parse(input).ok()?).map(|x| x.to_string()),
+            }],
+            run: |input, options| {
+                let (parsed, record) = execute_parse(parse, input, puzzle!(2025, 4), options);
+                let Some(parsed) = parsed else {
+                    return vec![record];
+                };
+                vec![
+                    record,
+                    execute_part(sum, &parsed, puzzle!(2025, 4), 1, options).1,
+                ]
+            },
+        },
+    ];
+
+    #[test]
+    fn finds_registered_days() {
+        let registry = Registry::new(SOLUTIONS);
+        assert_eq!(registry.get(puzzle!(2025, 3)).unwrap().parts.len(), 1);
+        assert!(registry.get(puzzle!(2025, 5)).is_none());
+        assert!(registry.get(puzzle!(2024, 3)).is_none());
+    }
+
+    #[test]
+    fn calls_registered_parts() {
+        let solution = Registry::new(SOLUTIONS).get(puzzle!(2025, 3)).unwrap();
+        assert_eq!((solution.parts[0].solve)("abc"), Some("3".into()));
+
+        let records = (solution.run)("abcd", &Default::default());
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].status, Status::Solved);
+        assert_eq!(records[0].answer, Some("4".into()));
+        assert_eq!(records[0].samples, 1);
+    }
+
+    #[test]
+    fn parses_input_once_for_all_parts() {
+        let solution = Registry::new(SOLUTIONS).get(puzzle!(2025, 4)).unwrap();
+        assert_eq!((solution.parts[0].solve)("1,2,3"), Some("6".into()));
+
+        let records = (solution.run)("1,2,3", &Default::default());
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].part, PARSE_PART);
+        assert_eq!(records[0].status, Status::Solved);
+        assert_eq!(records[0].answer, None);
+        assert_eq!(records[1].part, 1);
+        assert_eq!(records[1].answer, Some("6".into()));
+    }
+
+    #[test]
+    fn skips_parts_if_parsing_fails() {
+        let solution = Registry::new(SOLUTIONS).get(puzzle!(2025, 4)).unwrap();
+        let options = RunOptions {
+            quiet: true,
+            ..Default::default()
+        };
+        assert_eq!((solution.parts[0].solve)("1,x"), None);
+
+        let records = (solution.run)("1,x", &options);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].part, PARSE_PART);
+        assert_eq!(records[0].status, Status::Errored);
+        assert_eq!(
+            records[0].message,
+            Some(
+                "could not parse `x` as u64: invalid digit found in string at line 1, column 3"
+                    .into()
+            )
+        );
+
+        let (parsed, record) = execute_parse(
+            |_: &str| -> Result<u64, ParseError> { panic!("no input here") },
+            "",
+            puzzle!(2025, 4),
+            &options,
+        );
+        assert_eq!(parsed, None);
+        assert_eq!(record.status, Status::Panicked);
+    }
+
+    #[test]
+    fn accepts_infallible_and_fallible_parse_functions() {
+        mod infallible {
+            pub fn parse(input: &str) -> usize {
+                input.len()
+            }
+
+            pub fn run(input: &str) -> Result<usize, miette::Report> {
+                crate::solution!(@parse_input input)
+            }
+        }
+
+        mod fallible {
+            pub fn parse(input: &str) -> Result<u64, crate::parse::ParseError> {
+                crate::parse::Span::new(input).parse()
+            }
+
+            pub fn run(input: &str) -> Result<u64, crate::parse::ParseError> {
+                crate::solution!(@parse_input input)
+            }
+        }
+
+        assert_eq!(infallible::run("abc").unwrap(), 3);
+        assert_eq!(fallible::run("12").unwrap(), 12);
+        assert_eq!(fallible::run("1x").unwrap_err().location(), (1, 1));
+    }
+
+    #[test]
+    fn isolates_panicking_parts() {
+        let options = RunOptions {
+            quiet: true,
+            ..Default::default()
+        };
+        let (result, record) = execute_part(
+            |_: &str| -> Option<u64> { panic!("no answer here") },
+            "",
+            puzzle!(2025, 3),
+            2,
+            &options,
+        );
+        assert_eq!(result, None);
+        assert_eq!(record.status, Status::Panicked);
+        assert_eq!(record.message, Some("no answer here".into()));
+    }
+
+    #[test]
+    fn reports_parts_returning_errors() {
+        let options = RunOptions {
+            quiet: true,
+            ..Default::default()
+        };
+        let (result, record) = execute_part(
+            |input: &str| Span::new(input).parse::<u64>(),
+            "12\n",
+            puzzle!(2025, 3),
+            1,
+            &options,
+        );
+        assert_eq!(result, Some(Answer::Integer(12)));
+        assert_eq!(record.status, Status::Solved);
+
+        let (result, record) = execute_part(
+            |input: &str| Span::new(input).parse::<u64>(),
+            "1x\n",
+            puzzle!(2025, 3),
+            1,
+            &options,
+        );
+        assert_eq!(result, None);
+        assert_eq!(record.status, Status::Errored);
+        assert_eq!(
+            record.message,
+            Some(
+                "could not parse `1x` as u64: invalid digit found in string at line 1, column 1"
+                    .into()
+            )
+        );
+    }
+}
@@ -1,40 +1,109 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, fs, io, time::Duration};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use rayon::{ThreadPoolBuilder, prelude::*};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Puzzle, Year,
+    protocol::{PartRecord, Status},
+    registry::{Registry, Solution},
+    runner::{self, RunOptions},
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Options that control how a set of days is run.
+#[derive(Clone, Copy, Default)]
+pub struct MultiOptions {
+    /// Build solution binaries in release mode. Only applies to days that are not in the registry.
+    pub is_release: bool,
+    pub is_timed: bool,
+    pub warmup: Option<u32>,
+    /// Solutions that are run in-process. Days missing from the registry are run as child commands.
+    pub registry: Registry,
+    /// Run days concurrently and print their buffered output in day order once all are finished.
+    /// `Some(0)` uses one thread per available core, otherwise the number of threads.
+    pub parallel: Option<usize>,
+    /// Wall-clock limit for each part of a day.
+    pub part_timeout: Option<Duration>,
+    /// Wall-clock limit for running a day, after which its process is killed.
+    pub day_timeout: Option<Duration>,
+    /// Count heap allocations of each part. Builds solution binaries with the `alloc-stats` feature.
+    pub alloc_stats: bool,
+}
+
+impl MultiOptions {
+    /// Timeouts can only be enforced by killing a process, so days with a timeout are never run in-process.
+    /// Allocations can only be counted in-process if this binary was built with the `alloc-stats` feature,
+    /// and not in parallel, since the counters are shared by all threads of the process.
+    fn needs_child_process(&self) -> bool {
+        self.part_timeout.is_some()
+            || self.day_timeout.is_some()
+            || (self.alloc_stats && !cfg!(feature = "alloc-stats"))
+            || (self.alloc_stats && self.parallel.is_some())
+    }
+}
+
+/// Overall outcome of running a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// Every part returned an answer.
+    Solved,
+    /// The day has not been scaffolded yet or at least one part returned `None`.
+    Unsolved,
+    /// At least one part panicked.
+    Panicked,
+    /// At least one part returned an error, e.g. because of malformed input.
+    Errored,
+    /// A part or the day as a whole exceeded its timeout.
+    TimedOut,
+    /// The day could not be run.
+    Failed(String),
+}
 
-    let mut need_space = false;
+/// Result of running a single day.
+pub struct DayRun {
+    pub day: Day,
+    pub records: Vec<PartRecord>,
+    pub outcome: Outcome,
+    /// Output captured from a child command when running in parallel.
+    pub output: Vec<String>,
+}
 
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: &MultiOptions,
+) -> Option<Timings> {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days: Vec<Puzzle> = all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
-
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        .filter_map(|day| Puzzle::new(year, day))
+        .collect();
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let runs = match options.parallel {
+        Some(threads) => run_parallel(&days, options, threads),
+        None => days
+            .iter()
+            .enumerate()
+            .map(|(i, &puzzle)| {
+                print_header(puzzle.day, i > 0);
+                let run = run_day(puzzle, options, false);
+                print_outcome(&run);
+                run
+            })
+            .collect(),
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    let timings: Vec<Timing> = runs
+        .iter()
+        .filter(|run| !run.records.is_empty())
+        .map(|run| child_commands::timing_from_records(&run.records, run.day))
+        .collect();
 
-    if is_timed {
+    if options.is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -42,15 +111,159 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         );
         Some(timings)
     } else {
+        print_summary(&runs);
         None
     }
 }
 
+/// Run days without printing their output and return their results in day order.
+pub fn run_quiet(days: &[Puzzle], options: &MultiOptions) -> Vec<DayRun> {
+    match options.parallel {
+        Some(threads) => run_concurrently(days, options, threads),
+        None => days
+            .iter()
+            .map(|&puzzle| run_day(puzzle, options, true))
+            .collect(),
+    }
+}
+
+/// Run days concurrently, then print their output in day order.
+fn run_parallel(days: &[Puzzle], options: &MultiOptions, threads: usize) -> Vec<DayRun> {
+    let runs = run_concurrently(days, options, threads);
+
+    for (i, run) in runs.iter().enumerate() {
+        print_header(run.day, i > 0);
+
+        if run.output.is_empty() {
+            run.records.iter().for_each(runner::print_record);
+        } else {
+            run.output.iter().for_each(|line| println!("{line}"));
+        }
+
+        print_outcome(run);
+    }
+
+    runs
+}
+
+fn run_concurrently(days: &[Puzzle], options: &MultiOptions, threads: usize) -> Vec<DayRun> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("could not create thread pool");
+
+    pool.install(|| {
+        days.par_iter()
+            .map(|&puzzle| run_day(puzzle, options, true))
+            .collect()
+    })
+}
+
+/// Run a day in-process if it is registered, as a child command otherwise.
+/// When `buffered` is set, output is captured instead of being printed as it is produced.
+fn run_day(puzzle: Puzzle, options: &MultiOptions, buffered: bool) -> DayRun {
+    let registered = options
+        .registry
+        .get(puzzle)
+        .filter(|_| !options.needs_child_process());
+
+    let (records, output, failure) = match registered {
+        Some(solution) => match run_in_process(solution, options, buffered) {
+            Ok(records) => (records, vec![], None),
+            Err(e) => (vec![], vec![], Some(e)),
+        },
+        None => match child_commands::run_solution(puzzle, options, buffered) {
+            Ok(child) => {
+                let failure = (!child.success && child.records.is_empty())
+                    .then(|| "solution exited with an error.".into());
+                (child.records, child.output, failure)
+            }
+            Err(e) => (vec![], vec![], Some(format!("{e:?}"))),
+        },
+    };
+
+    let has_status = |status| records.iter().any(|r: &PartRecord| r.status == status);
+
+    let outcome = match failure {
+        Some(e) => Outcome::Failed(e),
+        None if has_status(Status::TimedOut) => Outcome::TimedOut,
+        None if has_status(Status::Panicked) => Outcome::Panicked,
+        None if has_status(Status::Errored) => Outcome::Errored,
+        None if records.is_empty() || has_status(Status::Unsolved) => Outcome::Unsolved,
+        None => Outcome::Solved,
+    };
+
+    DayRun {
+        day: puzzle.day,
+        records,
+        outcome,
+        output,
+    }
+}
+
+/// Run all parts of a registered solution in the current process.
+fn run_in_process(
+    solution: &Solution,
+    options: &MultiOptions,
+    quiet: bool,
+) -> Result<Vec<PartRecord>, String> {
+    let input_path = solution.puzzle.data_path("inputs");
+
+    let input = fs::read_to_string(&input_path)
+        .map_err(|e| format!("could not read input file \"{input_path}\": {e}"))?;
+
+    let run_options = RunOptions {
+        time: options.is_timed,
+        warmup: options.warmup.map_or(0, u128::from),
+        quiet,
+        timeout: None,
+    };
+
+    Ok((solution.run)(&input, &run_options))
+}
+
+fn print_header(day: Day, need_space: bool) {
+    if need_space {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+fn print_outcome(run: &DayRun) {
+    match &run.outcome {
+        Outcome::Unsolved if run.records.is_empty() => {
+            println!("Not solved.");
+        }
+        Outcome::Failed(e) => {
+            println!("Failed: {e}");
+        }
+        _ => {}
+    }
+}
+
+fn print_summary(runs: &[DayRun]) {
+    let count = |f: fn(&Outcome) -> bool| runs.iter().filter(|run| f(&run.outcome)).count();
+
+    let solved = count(|o| *o == Outcome::Solved);
+    let unsolved = count(|o| *o == Outcome::Unsolved);
+    let panicked = count(|o| *o == Outcome::Panicked);
+    let errored = count(|o| *o == Outcome::Errored);
+    let timed_out = count(|o| *o == Outcome::TimedOut);
+    let failed = count(|o| matches!(o, Outcome::Failed(_)));
+
+    println!(
+        "\n{ANSI_BOLD}Summary:{ANSI_RESET} {solved} solved, {unsolved} unsolved, {panicked} panicked, {errored} errored, {timed_out} timed out, {failed} failed"
+    );
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    Protocol(String),
 }
 
 impl From<std::io::Error> for Error {
@@ -59,48 +272,88 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting
+/// the result records they write to the side channel described in [`crate::template::protocol`].
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, MultiOptions};
+    use crate::template::{
+        Day, Puzzle,
+        protocol::{self, PARSE_PART, PartRecord, RESULTS_FILE_ENV, Status},
+        timings::PartTiming,
+    };
     use std::{
+        env, fs,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{self, Command, Stdio},
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Records and output of a solution bin.
+    pub struct ChildOutput {
+        pub records: Vec<PartRecord>,
+        /// Lines written to stdout and stderr. Only collected for buffered invocations.
+        pub output: Vec<String>,
+        pub success: bool,
+    }
+
+    /// Run the solution bin for a given day and return the result record of each part.
+    /// When `buffered` is set, output is collected instead of being forwarded to stdout/stderr.
+    pub fn run_solution(
+        puzzle: Puzzle,
+        options: &MultiOptions,
+        buffered: bool,
+    ) -> Result<ChildOutput, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return Ok(ChildOutput {
+                records: vec![],
+                output: vec![],
+                success: true,
+            });
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut args: Vec<String> = vec!["run".into(), "--quiet".into(), "--bin".into()];
+        args.push(puzzle.to_string());
+
+        if options.is_release {
+            args.push("--release".into());
+        }
 
-        if is_release {
-            args.push("--release");
+        if options.alloc_stats {
+            args.push("--features".into());
+            args.push("alloc-stats".into());
         }
 
-        if is_timed {
+        args.push("--".into());
+
+        if options.is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            args.push("--time".into());
+
+            if let Some(warmup) = options.warmup {
+                args.push("--warmup".into());
+                args.push(warmup.to_string());
+            }
         }
 
+        if let Some(timeout) = options.part_timeout {
+            args.push("--timeout".into());
+            args.push(timeout.as_secs_f64().to_string());
+        }
+
+        let results_path = get_results_path(puzzle);
+        // a stale file from an aborted run would otherwise be attributed to this run.
+        let _ = fs::remove_file(&results_path);
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward or collect output, results are read from the side channel afterwards.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
+            .env(RESULTS_FILE_ENV, &results_path)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -108,85 +361,113 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let stdout_thread =
+            thread::spawn(move || forward_lines(stdout, buffered, |line| println!("{line}")));
+        let stderr_thread =
+            thread::spawn(move || forward_lines(stderr, buffered, |line| eprintln!("{line}")));
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        let deadline = options.day_timeout.map(|timeout| Instant::now() + timeout);
+        let mut timed_out = false;
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let status = loop {
+            if let Some(status) = cmd.try_wait()? {
+                break status;
+            }
+
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                timed_out = true;
+                cmd.kill()?;
+                break cmd.wait()?;
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        let mut output = stdout_thread.join().unwrap();
+        output.extend(stderr_thread.join().unwrap());
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let mut records = protocol::read_records(&results_path).map_err(Error::Protocol);
+        let _ = fs::remove_file(&results_path);
 
-        Ok(output)
+        if let (Ok(records), Some(timeout)) =
+            (&mut records, options.day_timeout.filter(|_| timed_out))
+        {
+            let message = format!("day exceeded {timeout:.1?}");
+            if !buffered {
+                println!("Timed out: {message}");
+            }
+            add_timed_out_records(records, puzzle.day, &message);
+        }
+
+        Ok(ChildOutput {
+            records: records?,
+            output,
+            success: status.success(),
+        })
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Forward the lines of a child's output, or collect them if `buffered` is set.
+    fn forward_lines(reader: impl BufRead, buffered: bool, print: fn(&str)) -> Vec<String> {
+        let mut output = vec![];
+
+        for line in reader.lines().map_while(Result::ok) {
+            if buffered {
+                output.push(line);
+            } else {
+                print(&line);
+            }
+        }
 
         output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
-            });
+    }
 
-        timings
+    /// Mark parts that did not report a result before the day was killed as timed out.
+    fn add_timed_out_records(records: &mut Vec<PartRecord>, day: Day, message: &str) {
+        for part in 1..=2 {
+            if !records.iter().any(|record| record.part == part) {
+                records.push(PartRecord {
+                    day,
+                    part,
+                    status: Status::TimedOut,
+                    answer: None,
+                    message: Some(message.into()),
+                    nanos: 0_f64,
+                    samples: 1,
+                    stats: None,
+                    alloc: None,
+                });
+            }
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    fn get_results_path(puzzle: Puzzle) -> PathBuf {
+        env::temp_dir().join(format!("aoc-results-{}-{puzzle}.jsonl", process::id()))
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    pub fn timing_from_records(records: &[PartRecord], day: Day) -> super::Timing {
+        let part = |part: u8| {
+            records
+                .iter()
+                .find(|record| record.day == day && record.part == part)
+                .map(|record| PartTiming {
+                    status: record.status,
+                    nanos: if record.status == Status::Solved {
+                        record.nanos
+                    } else {
+                        0_f64
+                    },
+                    samples: record.samples,
+                    stats: record.stats.clone(),
+                    alloc: record.alloc,
+                })
+        };
+
+        super::Timing {
+            day,
+            parse: part(PARSE_PART),
+            part_1: part(1),
+            part_2: part(2),
+        }
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,53 +486,85 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::timing_from_records;
 
-        use crate::day;
+        use crate::{
+            day,
+            template::protocol::{PartRecord, Status},
+        };
+
+        fn record(part: u8, answer: Option<&str>, nanos: f64) -> PartRecord {
+            PartRecord {
+                day: day!(1),
+                part,
+                status: if answer.is_some() {
+                    Status::Solved
+                } else {
+                    Status::Unsolved
+                },
+                answer: answer.map(String::from),
+                message: None,
+                nanos,
+                samples: 100000,
+                stats: None,
+                alloc: None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    record(1, Some("0"), 74.13),
+                    record(2, Some("10"), 74130000.0),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos(), 74130074.13_f64);
+            assert_approx_eq!(res.part_1.unwrap().nanos, 74.13_f64);
+            let part_2 = res.part_2.unwrap();
+            assert_eq!(part_2.nanos, 74130000.0);
+            assert_eq!(part_2.samples, 100000);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_with_patterns_in_answers() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    record(1, Some("@ @ @ ( ) ms (2s @ 5 samples)"), 2e9),
+                    record(2, Some("10s (100ms @ 1 samples)"), 1e8),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos(), 2100000000_f64);
+            assert_eq!(res.part_1.unwrap().nanos, 2e9);
+            assert_eq!(res.part_2.unwrap().nanos, 1e8);
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+        fn collects_missing_parts() {
+            let res = timing_from_records(&[record(1, None, 0.0), record(2, None, 0.0)], day!(1));
+            assert_approx_eq!(res.total_nanos(), 0_f64);
+            assert_eq!(res.part_1.unwrap().status, Status::Unsolved);
+            assert_eq!(res.part_2.unwrap().solved_nanos(), None);
+        }
+
+        #[test]
+        fn collects_failed_parts() {
+            let panicked = PartRecord {
+                status: Status::Panicked,
+                message: Some("oops".into()),
+                ..record(1, None, 0.0)
+            };
+            let timed_out = PartRecord {
+                status: Status::TimedOut,
+                ..record(2, None, 5e9)
+            };
+
+            let res = timing_from_records(&[panicked, timed_out], day!(1));
+            assert_approx_eq!(res.total_nanos(), 0_f64);
+            assert_eq!(res.part_1.unwrap().status, Status::Panicked);
+            assert_eq!(res.part_2.unwrap().status, Status::TimedOut);
         }
     }
 }
@@ -1,60 +1,439 @@
 /// Encapsulates code that interacts with solution functions.
-use std::fmt::Display;
+use std::any::Any;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
+
+use miette::{Diagnostic, NarratableReportHandler, Report};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::alloc::{self, AllocStats};
+use crate::template::aoc_client::{self, AocError, SubmitResponse, Verdict};
+use crate::template::protocol::{PARSE_PART, PartRecord, Status, part_label};
+use crate::template::stats::Stats;
+use crate::template::submissions::Submissions;
+use crate::template::{
+    ANSI_ITALIC, ANSI_RESET, Answer, Day, PartOutput, Puzzle, answers, input_source,
+};
+
+/// Options that control how a solution part is executed.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Bench the part instead of executing it once.
+    pub time: bool,
+    /// Number of discarded iterations to run before benching.
+    pub warmup: u128,
+    /// Do not print results, e.g. when they are printed from records later on.
+    pub quiet: bool,
+    /// Wall-clock limit for the first execution of the part. A part that exceeds it can not be
+    /// interrupted, so the process reports it as timed out and exits. Only use this in solution binaries.
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    /// Read the options from the `--time`, `--warmup <n>` and `--timeout <seconds>` command-line arguments.
+    pub fn from_args() -> Self {
+        Self {
+            time: env::args().any(|x| x == "--time"),
+            warmup: parse_arg("--warmup", "--warmup <iterations>").unwrap_or(0),
+            quiet: false,
+            timeout: parse_arg("--timeout", "--timeout <seconds>").map(Duration::from_secs_f64),
+        }
+    }
+}
+
+pub fn run_part<I: Copy, O: PartOutput>(func: impl Fn(I) -> O, input: I, puzzle: Puzzle, part: u8) {
+    let (result, record) = execute_part(func, input, puzzle, part, &RunOptions::from_args());
+
+    if let Err(e) = record.emit() {
+        eprintln!("Failed to write result record: {e}");
+    }
+
+    let Some(result) = result else {
+        return;
+    };
+
+    match submit_result(&result, puzzle, part) {
+        Some(Ok(response)) => {
+            println!("{}", response.message);
+
+            if response.verdict == Verdict::Correct {
+                match answers::store_accepted(puzzle, part, &result.to_string()) {
+                    Ok(()) => println!(
+                        "🎄 Stored accepted answer for day {} of {}, part {part}.",
+                        puzzle.day, puzzle.year
+                    ),
+                    Err(e) => eprintln!("Failed to store accepted answer: {e}"),
+                }
+            }
+        }
+        Some(Err(e)) => {
+            eprintln!("Failed to submit result: {e}");
+            process::exit(1);
+        }
+        None => {}
+    }
+}
+
+/// Parse the input of a solution with its `parse` function and emit a record of it.
+/// Returns `None` if parsing panicked or failed, in which case the parts can not be run.
+pub fn run_parse<T, E: Into<Report>>(
+    func: impl Fn(&str) -> Result<T, E>,
+    input: &str,
+    puzzle: Puzzle,
+) -> Option<T> {
+    let (parsed, record) = execute_parse(func, input, puzzle, &RunOptions::from_args());
+
+    if let Err(e) = record.emit() {
+        eprintln!("Failed to write result record: {e}");
+    }
+
+    parsed
+}
+
+/// Execute the `parse` function of a solution, print its timing and return the parsed input together
+/// with a record for reporting. The record is reported as [`PARSE_PART`], errors with [`Status::Errored`].
+pub fn execute_parse<T, E: Into<Report>>(
+    func: impl Fn(&str) -> Result<T, E>,
+    input: &str,
+    puzzle: Puzzle,
+    options: &RunOptions,
+) -> (Option<T>, PartRecord) {
+    let (output, mut record) = execute(
+        |input| func(input).map_err(Into::into),
+        input,
+        puzzle,
+        PARSE_PART,
+        options,
+        print_parse_output,
+    );
+
+    match output {
+        Some(Ok(parsed)) => (Some(parsed), record),
+        Some(Err(report)) => {
+            record.status = Status::Errored;
+            record.message = Some(report.to_string());
+            (None, record)
+        }
+        None => (None, record),
+    }
+}
+
+/// Lets `parse` functions return either the parsed input or a [`Result`] whose error is a
+/// [`Diagnostic`]. Both are converted to a `Result` for [`execute_parse`].
+///
+/// A blanket implementation for any value would overlap with the one for `Result`, so the
+/// `solution!` macro picks the conversion by auto-ref: `(&parsed).parse_kind().into_result(parsed)`
+/// finds the method for `Result` before the one for references to any value.
+#[doc(hidden)]
+pub trait FallibleParse {
+    fn parse_kind(&self) -> FallibleKind {
+        FallibleKind
+    }
+}
+
+impl<T, E: Diagnostic + Send + Sync + 'static> FallibleParse for Result<T, E> {}
+
+#[doc(hidden)]
+pub trait InfallibleParse {
+    fn parse_kind(&self) -> InfallibleKind {
+        InfallibleKind
+    }
+}
+
+impl<T> InfallibleParse for &T {}
+
+#[doc(hidden)]
+pub struct FallibleKind;
+
+impl FallibleKind {
+    pub fn into_result<T, E>(self, parsed: Result<T, E>) -> Result<T, E> {
+        parsed
+    }
+}
+
+#[doc(hidden)]
+pub struct InfallibleKind;
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+impl InfallibleKind {
+    pub fn into_result<T>(self, parsed: T) -> Result<T, Report> {
+        Ok(parsed)
+    }
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// Execute a solution part, print its answer and return it together with a record for reporting.
+/// Panics are caught and reported with [`Status::Panicked`], errors with [`Status::Errored`].
+pub fn execute_part<I: Copy, O: PartOutput>(
+    func: impl Fn(I) -> O,
+    input: I,
+    puzzle: Puzzle,
+    part: u8,
+    options: &RunOptions,
+) -> (Option<Answer>, PartRecord) {
+    let (output, mut record) = execute(
+        |input| func(input).into_output(),
+        input,
+        puzzle,
+        part,
+        options,
+        print_output,
+    );
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let result = match output {
+        Some(Ok(result)) => result,
+        Some(Err(report)) => {
+            record.status = Status::Errored;
+            record.message = Some(report.to_string());
+            return (None, record);
+        }
+        None => return (None, record),
+    };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    record.status = match result {
+        Some(Answer::NotApplicable) => Status::NotApplicable,
+        Some(_) => Status::Solved,
+        None => Status::Unsolved,
+    };
+
+    if record.status == Status::Solved {
+        record.answer = result.as_ref().map(ToString::to_string);
+    }
+
+    (result, record)
+}
+
+/// Execute a function once or bench it, depending on the options. Returns `None` if it panicked,
+/// otherwise its result and a record with [`Status::Solved`] for the caller to refine.
+fn execute<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    puzzle: Puzzle,
+    part: u8,
+    options: &RunOptions,
+    print: impl Fn(&T, &str, &str),
+) -> (Option<T>, PartRecord) {
+    let day = puzzle.day;
+    let part_str = part_label(part);
+    let watchdog = options
+        .timeout
+        .map(|timeout| Watchdog::start(timeout, day, part));
+
+    let run = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_timed(&func, input, options, |result| {
+            if let Some(watchdog) = &watchdog {
+                watchdog.finish();
+            }
+
+            if !options.quiet {
+                print(result, &part_str, "");
+            }
+        })
+    }));
+
+    let mut record = PartRecord {
+        day,
+        part,
+        status: Status::Panicked,
+        answer: None,
+        message: None,
+        nanos: 0_f64,
+        samples: 1,
+        stats: None,
+        alloc: None,
+    };
+
+    let (result, duration, stats, alloc) = match run {
+        Ok(run) => run,
+        Err(payload) => {
+            record.message = Some(panic_message(payload.as_ref()));
+            if !options.quiet {
+                print_record(&record);
+            }
+            return (None, record);
+        }
+    };
+
+    let samples = stats.as_ref().map_or(1, |stats| stats.samples);
+
+    if !options.quiet {
+        print(&result, &part_str, &format_duration(&duration, samples));
+
+        if let Some(stats) = &stats {
+            print_stats(stats);
+        }
+
+        if let Some(alloc) = &alloc {
+            print_alloc(alloc);
+        }
+    }
+
+    record.status = Status::Solved;
+    record.nanos = duration.as_nanos() as f64;
+    record.samples = samples;
+    record.stats = stats;
+    record.alloc = alloc;
+
+    (Some(result), record)
+}
+
+/// Print the result of a part from its record, e.g. after it was executed with [`RunOptions::quiet`].
+pub fn print_record(record: &PartRecord) {
+    let part_str = part_label(record.part);
+
+    match record.status {
+        Status::Solved | Status::Unsolved | Status::NotApplicable => {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let duration = Duration::from_nanos(record.nanos as u64);
+            let duration_str = format_duration(&duration, record.samples);
+
+            if record.part == PARSE_PART {
+                print_parsed(&part_str, &duration_str);
+            } else {
+                let answer = match record.status {
+                    Status::NotApplicable => Some(Answer::NotApplicable),
+                    _ => record.answer.as_deref().map(Answer::from_text),
+                };
+                print_result(&answer, &part_str, &duration_str);
+            }
+
+            if let Some(stats) = &record.stats {
+                print_stats(stats);
+            }
+
+            if let Some(alloc) = &record.alloc {
+                print_alloc(alloc);
+            }
+        }
+        Status::Panicked | Status::TimedOut | Status::Errored => {
+            let message = record.message.as_deref().unwrap_or_default();
+            print!("\r");
+            println!("{part_str}: ✖ {}: {message}", record.status.as_str());
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+/// Ends the process if a part does not finish within its timeout, since the part can not be interrupted.
+/// Before exiting, the part is reported with [`Status::TimedOut`].
+struct Watchdog {
+    done: Sender<()>,
+}
+
+impl Watchdog {
+    fn start(timeout: Duration, day: Day, part: u8) -> Self {
+        let (done, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            // a disconnect means the part has finished or panicked.
+            if let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(timeout) {
+                let record = PartRecord {
+                    day,
+                    part,
+                    status: Status::TimedOut,
+                    answer: None,
+                    message: Some(format!("exceeded {timeout:.1?}")),
+                    nanos: timeout.as_nanos() as f64,
+                    samples: 1,
+                    stats: None,
+                    alloc: None,
+                };
+
+                print_record(&record);
+                let _ = record.emit();
+                process::exit(1);
+            }
+        });
+
+        Self { done }
+    }
+
+    fn finish(&self) {
+        let _ = self.done.send(());
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// When benched, the returned duration is the mean of all samples and statistics are returned alongside it.
+/// Allocations are counted for the first execution if the `alloc-stats` feature is enabled.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<Stats>, Option<AllocStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, alloc) = alloc::measure(|| {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
         func(input)
-    };
+    });
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if options.time {
+        let stats = bench(func, input, &base_time, options);
+        (result, stats.mean, Some(stats), alloc)
     } else {
-        (base_time, 1)
-    };
+        (result, base_time, None, alloc)
+    }
+}
 
-    (result, run.0, run.1)
+/// Read the value of a `--name <value>` argument, exits if the value can not be parsed.
+fn parse_arg<T: std::str::FromStr>(name: &str, format: &str) -> Option<T> {
+    let args: Vec<String> = env::args().collect();
+
+    args.iter()
+        .position(|x| x == name)
+        .and_then(|i| args.get(i + 1))
+        .map(|x| {
+            x.parse().unwrap_or_else(|_| {
+                eprintln!("Unexpected command-line input. Format: {format}");
+                process::exit(1);
+            })
+        })
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    options: &RunOptions,
+) -> Stats {
     let mut stdout = stdout();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    if options.warmup > 0 {
+        if !options.quiet {
+            print!(" > {ANSI_ITALIC}warming up{ANSI_RESET}");
+            let _ = stdout.flush();
+        }
+
+        // warm-up samples are discarded and do not count towards the statistics.
+        for _ in 0..options.warmup {
+            black_box(func(black_box(input)));
+        }
+    }
+
+    if !options.quiet {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout.flush();
+    }
 
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
@@ -67,22 +446,10 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers).expect("bench collects at least 10 samples")
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
-
-fn format_duration(duration: &Duration, samples: u128) -> String {
+fn format_duration(duration: &Duration, samples: usize) -> String {
     if samples == 1 {
         format!(" ({duration:.1?})")
     } else {
@@ -90,28 +457,99 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_stats(stats: &Stats) {
+    println!(
+        "        {ANSI_ITALIC}min {:.1?} · median {:.1?} · p95 {:.1?} · p99 {:.1?} · max {:.1?} · σ {:.1?} · {} outlier(s){ANSI_RESET}",
+        stats.min, stats.median, stats.p95, stats.p99, stats.max, stats.std_dev, stats.outliers
+    );
+}
+
+fn print_alloc(alloc: &AllocStats) {
+    println!(
+        "        {ANSI_ITALIC}{} allocation(s) · {} allocated · {} peak heap{ANSI_RESET}",
+        alloc.allocations,
+        alloc::format_bytes(alloc.bytes),
+        alloc::format_bytes(alloc.peak_bytes)
+    );
+}
+
+/// Render an error returned by a part, including a snippet of the input if it points at one.
+fn render_diagnostic(report: &Report) -> String {
+    let mut rendered = String::new();
+
+    if NarratableReportHandler::new()
+        .render_report(&mut rendered, report.as_ref())
+        .is_err()
+    {
+        return report.to_string();
+    }
+
+    rendered.trim_end().to_string()
+}
+
+fn print_output(output: &Result<Option<Answer>, Report>, part: &str, duration_str: &str) {
+    match output {
+        Ok(result) => print_result(result, part, duration_str),
+        Err(report) => print_error(report, part, duration_str),
+    }
+}
+
+fn print_parse_output<T>(output: &Result<T, Report>, label: &str, duration_str: &str) {
+    match output {
+        Ok(_) => print_parsed(label, duration_str),
+        Err(report) => print_error(report, label, duration_str),
+    }
+}
+
+fn print_error(report: &Report, part: &str, duration_str: &str) {
+    if duration_str.is_empty() {
+        print!("{part}: ✖");
+    } else {
+        print!("\r");
+        println!(
+            "{part}: ✖ {}: {}",
+            Status::Errored.as_str(),
+            render_diagnostic(report)
+        );
+    }
+}
+
+/// Print the timing of the `parse` function, which has no answer to show.
+fn print_parsed(label: &str, duration_str: &str) {
+    if duration_str.is_empty() {
+        print!("{label}:");
+    } else {
+        print!("\r");
+        println!("{label}:{duration_str}");
+    }
+}
+
+fn print_result(result: &Option<Answer>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
+        Some(Answer::Grid(grid)) => {
+            let str = format!("{part}: ▼ {duration_str}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}");
+                println!("{grid}");
+            }
+        }
         Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
+            let str = if *result == Answer::NotApplicable {
+                format!("{part}: {ANSI_ITALIC}{result}{ANSI_RESET}{duration_str}")
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
+                format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}")
+            };
+
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}");
             }
         }
         None => {
@@ -127,12 +565,13 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
+///  2. a backend for the website is configured.
+///  3. the answer fits on a single line and is not known to be wrong from earlier submissions.
+fn submit_result(
+    result: &Answer,
+    puzzle: Puzzle,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<SubmitResponse, AocError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,13 +594,52 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
+    let source = input_source();
+    if !source.is_puzzle_input() {
         eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            "Refusing to submit part {part}: the solution ran on {source}, not on the puzzle input."
         );
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = match result.submission() {
+        Ok(answer) => answer,
+        Err(reason) => {
+            eprintln!("Refusing to submit part {part}: {reason}");
+            process::exit(1);
+        }
+    };
+
+    let mut submissions = match Submissions::read_from_file(puzzle.year) {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Failed to read submissions: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = submissions.check(puzzle.day, part, &answer) {
+        eprintln!("Refusing to submit {answer}: {refusal}");
+        process::exit(1);
+    }
+
+    let backend = match aoc_client::backend() {
+        Ok(backend) => backend,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    println!("Submitting result...");
+    let response = backend.submit(puzzle, part, &answer);
+
+    if let Ok(response) = &response {
+        submissions.record(puzzle.day, part, &answer, response);
+        if let Err(e) = submissions.store_file(puzzle.year) {
+            eprintln!("Failed to store submission: {e}");
+        }
+    }
+
+    Some(response)
 }
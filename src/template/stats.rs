@@ -0,0 +1,193 @@
+//! Summary statistics over the samples collected while benching a solution part.
+use std::{collections::HashMap, time::Duration};
+
+use tinyjson::JsonValue;
+
+/// Statistical summary of a set of benchmark samples.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    /// Number of samples outside of the 1.5 × IQR fences (Tukey's method).
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Computes statistics for the given samples, returns [`None`] if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        let count = sorted.len();
+        let mean = sorted.iter().sum::<u128>() as f64 / count as f64;
+
+        let variance = sorted
+            .iter()
+            .map(|&x| (x as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        let q1 = percentile(&sorted, 25.0) as f64;
+        let q3 = percentile(&sorted, 75.0) as f64;
+        let iqr = q3 - q1;
+        let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        let outliers = sorted
+            .iter()
+            .filter(|&&x| (x as f64) < lower_fence || (x as f64) > upper_fence)
+            .count();
+
+        Some(Self {
+            samples: count,
+            mean: nanos_to_duration(mean),
+            min: nanos_to_duration(sorted[0] as f64),
+            median: nanos_to_duration(percentile(&sorted, 50.0) as f64),
+            p95: nanos_to_duration(percentile(&sorted, 95.0) as f64),
+            p99: nanos_to_duration(percentile(&sorted, 99.0) as f64),
+            max: nanos_to_duration(sorted[count - 1] as f64),
+            std_dev: nanos_to_duration(variance.sqrt()),
+            outliers,
+        })
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn nanos_to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Durations are serialized as nanoseconds.
+impl From<&Stats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        for (key, duration) in [
+            ("mean", value.mean),
+            ("min", value.min),
+            ("median", value.median),
+            ("p95", value.p95),
+            ("p99", value.p99),
+            ("max", value.max),
+            ("std_dev", value.std_dev),
+        ] {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(nanos_to_duration);
+
+        Ok(Stats {
+            samples: number("samples")? as usize,
+            mean: duration("mean")?,
+            min: duration("min")?,
+            median: duration("median")?,
+            p95: duration("p95")?,
+            p99: duration("p99")?,
+            max: duration("max")?,
+            std_dev: duration("std_dev")?,
+            outliers: number("outliers")? as usize,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = Stats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p99, Duration::from_nanos(42));
+        assert_eq!(stats.max, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_order_statistics() {
+        let samples: Vec<u64> = (1..=100).rev().collect();
+        let stats = Stats::from_samples(&nanos(&samples)).unwrap();
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(50));
+        assert_eq!(stats.p95, Duration::from_nanos(95));
+        assert_eq!(stats.p99, Duration::from_nanos(99));
+        assert_eq!(stats.max, Duration::from_nanos(100));
+        assert_eq!(stats.mean, Duration::from_nanos(51));
+    }
+
+    #[test]
+    fn computes_standard_deviation() {
+        let stats = Stats::from_samples(&nanos(&[2, 4, 4, 4, 5, 5, 7, 9])).unwrap();
+        assert_eq!(stats.mean, Duration::from_nanos(5));
+        assert_eq!(stats.std_dev, Duration::from_nanos(2));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let stats = Stats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 11, 500])).unwrap();
+        let parsed = Stats::try_from(&JsonValue::from(&stats)).unwrap();
+        assert_eq!(parsed, stats);
+    }
+
+    #[test]
+    fn counts_outliers() {
+        let stats = Stats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 11, 500])).unwrap();
+        assert_eq!(stats.outliers, 1);
+    }
+}
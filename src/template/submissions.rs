@@ -0,0 +1,366 @@
+//! Log of submitted answers per year, used to avoid resubmitting answers that are known to be wrong.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    Day, Year,
+    aoc_client::{Hint, SubmitResponse, Verdict},
+};
+
+static SUBMISSIONS_FILE_NAME: &str = "submissions.json";
+
+/// A single submitted answer and how the website responded to it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub hint: Option<Hint>,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Reason for refusing to submit an answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Refusal {
+    /// The answer was submitted before and was wrong.
+    KnownWrong(Option<Hint>),
+    /// A lower or equal answer was submitted before and was too high.
+    AboveUpperBound(String),
+    /// A higher or equal answer was submitted before and was too low.
+    BelowLowerBound(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::KnownWrong(Some(hint)) => {
+                write!(f, "it was submitted before and was {}.", hint.as_str())
+            }
+            Refusal::KnownWrong(None) => write!(f, "it was submitted before and was wrong."),
+            Refusal::AboveUpperBound(bound) => {
+                write!(f, "{bound} was submitted before and was too high.")
+            }
+            Refusal::BelowLowerBound(bound) => {
+                write!(f, "{bound} was submitted before and was too low.")
+            }
+        }
+    }
+}
+
+/// Log of all submissions.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty log.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(file_path(year)) {
+            Ok(contents) => Submissions::try_from(contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Append the response to a submitted answer to the log. Surrounding whitespace is not stored.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, response: &SubmitResponse) {
+        self.data.push(Submission {
+            day,
+            part,
+            answer: answer.trim().into(),
+            verdict: response.verdict,
+            hint: response.hint,
+            submitted_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |x| x.as_secs()),
+        });
+    }
+
+    /// Check an answer against earlier submissions of the same part, ignoring surrounding whitespace.
+    /// Bounds from "too high" / "too low" hints only apply to integer answers.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+        let wrong: Vec<&Submission> = self
+            .data
+            .iter()
+            .filter(|x| x.day == day && x.part == part && x.verdict == Verdict::Incorrect)
+            .collect();
+
+        if let Some(known) = wrong.iter().find(|x| x.answer.trim() == answer.trim()) {
+            return Err(Refusal::KnownWrong(known.hint));
+        }
+
+        let Ok(value) = answer.trim().parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |hint: Hint| {
+            wrong
+                .iter()
+                .filter(move |x| x.hint == Some(hint))
+                .filter_map(|x| Some((x.answer.trim().parse::<i128>().ok()?, x)))
+        };
+
+        if let Some((_, upper)) = bound(Hint::TooHigh)
+            .filter(|(bound, _)| value >= *bound)
+            .min_by_key(|(bound, _)| *bound)
+        {
+            return Err(Refusal::AboveUpperBound(upper.answer.clone()));
+        }
+
+        if let Some((_, lower)) = bound(Hint::TooLow)
+            .filter(|(bound, _)| value <= *bound)
+            .max_by_key(|(bound, _)| *bound)
+        {
+            return Err(Refusal::BelowLowerBound(lower.answer.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Path of the file for a year, e.g. `data/2025/submissions.json`.
+fn file_path(year: Year) -> String {
+    format!("{}/{}", year.data_dir(), SUBMISSIONS_FILE_NAME)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+        map.insert(
+            "hint".into(),
+            value
+                .hint
+                .map_or(JsonValue::Null, |x| JsonValue::String(x.as_str().into())),
+        );
+        map.insert(
+            "submitted_at".into(),
+            JsonValue::Number(value.submitted_at as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        let hint = json
+            .get("hint")
+            .and_then(|v| v.get::<String>())
+            .map(|x| x.parse())
+            .transpose()?;
+
+        let submitted_at = json
+            .get("submitted_at")
+            .and_then(|v| v.get::<f64>())
+            .map_or(0, |x| *x as u64);
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+            hint,
+            submitted_at,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use crate::{
+        day,
+        template::aoc_client::{Hint, SubmitResponse, Verdict},
+    };
+
+    use super::{Refusal, Submissions};
+
+    fn response(verdict: Verdict, hint: Option<Hint>) -> SubmitResponse {
+        SubmitResponse {
+            verdict,
+            hint,
+            message: String::new(),
+        }
+    }
+
+    fn get_mock_submissions() -> Submissions {
+        let mut submissions = Submissions::default();
+        submissions.record(
+            day!(1),
+            1,
+            "500",
+            &response(Verdict::Incorrect, Some(Hint::TooHigh)),
+        );
+        submissions.record(
+            day!(1),
+            1,
+            "800",
+            &response(Verdict::Incorrect, Some(Hint::TooHigh)),
+        );
+        submissions.record(
+            day!(1),
+            1,
+            "100",
+            &response(Verdict::Incorrect, Some(Hint::TooLow)),
+        );
+        submissions.record(day!(1), 1, "abc", &response(Verdict::Incorrect, None));
+        submissions.record(day!(1), 1, "300", &response(Verdict::TooSoon, None));
+        submissions
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            submissions.check(day!(1), 1, "abc"),
+            Err(Refusal::KnownWrong(None))
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "800"),
+            Err(Refusal::KnownWrong(Some(Hint::TooHigh)))
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, " abc\n"),
+            Err(Refusal::KnownWrong(None))
+        );
+    }
+
+    #[test]
+    fn refuses_answers_outside_of_bounds() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            submissions.check(day!(1), 1, "600"),
+            Err(Refusal::AboveUpperBound("500".into()))
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "-4"),
+            Err(Refusal::BelowLowerBound("100".into()))
+        );
+    }
+
+    #[test]
+    fn allows_answers_within_bounds() {
+        let submissions = get_mock_submissions();
+        assert_eq!(submissions.check(day!(1), 1, "499"), Ok(()));
+        assert_eq!(submissions.check(day!(1), 1, "101"), Ok(()));
+        // answers that were not judged can be submitted again.
+        assert_eq!(submissions.check(day!(1), 1, "300"), Ok(()));
+        assert_eq!(submissions.check(day!(1), 1, "xyz"), Ok(()));
+    }
+
+    #[test]
+    fn scopes_checks_to_day_and_part() {
+        let submissions = get_mock_submissions();
+        assert_eq!(submissions.check(day!(1), 2, "abc"), Ok(()));
+        assert_eq!(submissions.check(day!(2), 1, "900"), Ok(()));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let submissions = get_mock_submissions();
+        let json = JsonValue::from(submissions.clone()).stringify().unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+        assert_eq!(parsed.data, submissions.data);
+    }
+}
@@ -0,0 +1,205 @@
+//! Module templates used by `scaffold`.
+//!
+//! Besides the built-in templates, teams can keep their own in `templates/<name>.txt`, which take
+//! precedence over built-in templates of the same name. Templates can use these placeholders:
+//!
+//! - `%YEAR%`: year of the event, e.g. `2025`.
+//! - `%DAY_NUMBER%`: day without padding, e.g. `1`.
+//! - `%DAY%`: day padded to two digits, e.g. `01`.
+//! - `%TITLE%`: title of the puzzle if its description was downloaded, e.g. `Day 1: Secret Entrance`.
+//! - `%ANSWER_TYPE%`: type returned by the parts, `u64` unless specified.
+use std::{fs, io, path::Path};
+
+use crate::template::Puzzle;
+
+/// Directory of user templates, relative to the project root.
+pub const USER_TEMPLATE_DIR: &str = "templates";
+
+/// Name of the template used if none is selected.
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Answer type used if none is selected.
+pub const DEFAULT_ANSWER_TYPE: &str = "u64";
+
+const BUILT_IN_TEMPLATES: [(&str, &str); 4] = [
+    (
+        DEFAULT_TEMPLATE,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt")),
+    ),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "graph",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/graph.txt"
+        )),
+    ),
+    (
+        "parse-once",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/parse-once.txt"
+        )),
+    ),
+];
+
+/// Read a template by name, preferring a user template over a built-in one.
+pub fn load(name: &str) -> Result<String, String> {
+    let path = format!("{USER_TEMPLATE_DIR}/{name}.txt");
+
+    match fs::read_to_string(&path) {
+        Ok(template) => return Ok(template),
+        Err(e) if e.kind() != io::ErrorKind::NotFound => {
+            return Err(format!("could not read template \"{path}\": {e}"));
+        }
+        Err(_) => {}
+    }
+
+    BUILT_IN_TEMPLATES
+        .iter()
+        .find(|(x, _)| *x == name)
+        .map(|(_, template)| template.to_string())
+        .ok_or_else(|| {
+            format!(
+                "unknown template `{name}`. Available templates: {}.",
+                available().join(", ")
+            )
+        })
+}
+
+/// Names of all templates, built-in ones first.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = BUILT_IN_TEMPLATES
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+    if let Ok(entries) = fs::read_dir(Path::new(USER_TEMPLATE_DIR)) {
+        let mut user: Vec<String> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter_map(|name| name.strip_suffix(".txt").map(String::from))
+            .filter(|name| !names.contains(name))
+            .collect();
+        user.sort();
+        names.extend(user);
+    }
+
+    names
+}
+
+/// Values of the placeholders for a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Placeholders {
+    pub puzzle: Puzzle,
+    pub title: String,
+    pub answer_type: String,
+}
+
+impl Placeholders {
+    /// Use the title of the puzzle description if it was downloaded, `Day <day>` otherwise.
+    pub fn new(puzzle: Puzzle, answer_type: &str) -> Self {
+        let title = fs::read_to_string(puzzle.puzzle_path())
+            .ok()
+            .and_then(|markdown| puzzle_title(&markdown))
+            .unwrap_or_else(|| format!("Day {}", puzzle.day.into_inner()));
+
+        Self {
+            puzzle,
+            title,
+            answer_type: answer_type.into(),
+        }
+    }
+}
+
+/// Replace the placeholders of a template.
+pub fn render(template: &str, placeholders: &Placeholders) -> String {
+    let puzzle = placeholders.puzzle;
+
+    template
+        .replace("%YEAR%", &puzzle.year.to_string())
+        .replace("%DAY_NUMBER%", &puzzle.day.into_inner().to_string())
+        .replace("%DAY%", &puzzle.day.to_string())
+        .replace("%TITLE%", &placeholders.title)
+        .replace("%ANSWER_TYPE%", &placeholders.answer_type)
+}
+
+/// Title of a puzzle from the heading of its description, e.g. `Day 1: Secret Entrance`.
+pub fn puzzle_title(markdown: &str) -> Option<String> {
+    markdown
+        .lines()
+        .map(|line| line.trim_start_matches(['#', '\\', ' ']))
+        .find_map(|line| line.strip_prefix("--- ")?.strip_suffix(" ---"))
+        .filter(|title| title.starts_with("Day "))
+        .map(String::from)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BUILT_IN_TEMPLATES, Placeholders, available, load, puzzle_title, render};
+    use crate::puzzle;
+
+    fn placeholders() -> Placeholders {
+        Placeholders {
+            puzzle: puzzle!(2025, 3),
+            title: "Day 3: Lobby".into(),
+            answer_type: "usize".into(),
+        }
+    }
+
+    #[test]
+    fn replaces_placeholders() {
+        let template = "%YEAR% %DAY_NUMBER% %DAY% %TITLE% Option<%ANSWER_TYPE%>";
+        assert_eq!(
+            render(template, &placeholders()),
+            "2025 3 03 Day 3: Lobby Option<usize>"
+        );
+    }
+
+    #[test]
+    fn renders_all_placeholders_of_built_in_templates() {
+        for (name, template) in BUILT_IN_TEMPLATES {
+            let module = render(template, &placeholders());
+            assert!(
+                !module.contains('%'),
+                "template `{name}` has unknown placeholders"
+            );
+            assert!(
+                module.contains("fn test_part_one()"),
+                "template `{name}` has no tests"
+            );
+        }
+    }
+
+    #[test]
+    fn loads_built_in_templates() {
+        assert!(load("grid").unwrap().contains("Grid::parse"));
+        assert!(
+            load("nope")
+                .unwrap_err()
+                .contains("default, grid, graph, parse-once")
+        );
+        assert!(available().starts_with(&["default".into(), "grid".into()]));
+    }
+
+    #[test]
+    fn finds_puzzle_titles() {
+        assert_eq!(
+            puzzle_title("## --- Day 3: Lobby ---\n\nText.\n\n## --- Part Two ---"),
+            Some("Day 3: Lobby".into())
+        );
+        assert_eq!(
+            puzzle_title("## \\--- Day 3: Lobby ---"),
+            Some("Day 3: Lobby".into())
+        );
+        assert_eq!(puzzle_title("No heading."), None);
+    }
+}
@@ -1,17 +1,79 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    Day, Year,
+    alloc::AllocStats,
+    protocol::{PARSE_PART, Status},
+    stats::Stats,
+};
+
+static TIMINGS_FILE_NAME: &str = "timings.json";
+
+/// Location of the timings file before timings were stored per year.
+static LEGACY_TIMINGS_PATH: &str = "data/timings.json";
+
+/// Version of the timings file format. Files without a version are read as version 1, which stored
+/// Debug-formatted durations like `"74.1ms"` per part.
+const TIMINGS_VERSION: u32 = 2;
+
+/// Benchmark result of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    /// How the last run of the part ended, e.g. whether it panicked or returned `None`.
+    pub status: Status,
+    /// Execution time in nanoseconds. When benched, this is the mean of all samples.
+    pub nanos: f64,
+    /// Number of samples, `0` if unknown because the timing was migrated from version 1.
+    pub samples: usize,
+    pub stats: Option<Stats>,
+    /// Heap usage, if allocations were counted.
+    pub alloc: Option<AllocStats>,
+}
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+impl PartTiming {
+    /// Execution time in nanoseconds, if the part returned an answer.
+    pub fn solved_nanos(&self) -> Option<f64> {
+        (self.status == Status::Solved).then_some(self.nanos)
+    }
+}
 
 /// Represents benchmark times for a single day.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    /// Timing of the `parse` function, if the solution parses its input once for both parts.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+}
+
+impl Timing {
+    /// Timing of a part, or of the `parse` function for [`PARSE_PART`].
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            PARSE_PART => self.parse.as_ref(),
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Combined heap usage of parsing and all parts, if allocations were counted for any of them.
+    pub fn alloc(&self) -> Option<AllocStats> {
+        [&self.parse, &self.part_1, &self.part_2]
+            .into_iter()
+            .filter_map(|x| x.as_ref()?.alloc)
+            .reduce(AllocStats::combine)
+    }
+
+    /// Sum of the execution times of parsing and all solved parts in nanoseconds.
+    pub fn total_nanos(&self) -> f64 {
+        [&self.parse, &self.part_1, &self.part_2]
+            .into_iter()
+            .filter_map(|x| x.as_ref()?.solved_nanos())
+            .sum()
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -22,16 +84,28 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to a JSON file. Always writes the current version of the format.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(file_path(year))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    ///
+    /// Timings stored before they were namespaced by year are read from `data/timings.json` as
+    /// timings of the year configured in `AOC_YEAR`.
+    pub fn read_from_file(year: Year) -> Self {
+        let path = file_path(year);
+
+        let path = if fs::exists(&path).unwrap_or(false) || Year::from_env() != Some(year) {
+            path
+        } else {
+            LEGACY_TIMINGS_PATH.into()
+        };
+
+        fs::read_to_string(path)
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,28 +125,42 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.data.iter().map(Timing::total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Whether every part of a day was benched, parts without an answer count as benched.
     pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+        self.data.iter().any(|t| {
+            t.day == day
+                && [&t.part_1, &t.part_2].into_iter().all(|x| {
+                    x.as_ref()
+                        .is_some_and(|x| matches!(x.status, Status::Solved | Status::NotApplicable))
+                })
+        })
     }
 }
 
+/// Path of the timings file for a year, e.g. `data/2025/timings.json`.
+fn file_path(year: Year) -> String {
+    format!("{}/{}", year.data_dir(), TIMINGS_FILE_NAME)
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(TIMINGS_VERSION)),
+        );
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -85,21 +173,39 @@ impl From<Timings> for JsonValue {
 impl TryFrom<String> for Timings {
     type Error = String;
 
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let version = json
+            .get("version")
+            .and_then(|v| v.get::<f64>())
+            .map_or(1, |x| *x as u32);
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        if version != 1 && version != TIMINGS_VERSION {
+            return Err(format!("unsupported timings version {version}."));
+        }
+
         Ok(Timings {
             data: json_data
                 .iter()
-                .map(Timing::try_from)
+                .map(|x| {
+                    if version == 1 {
+                        Timing::try_from_v1(x)
+                    } else {
+                        Timing::try_from(x)
+                    }
+                })
                 .collect::<Result<_, _>>()?,
         })
     }
@@ -112,26 +218,17 @@ impl From<&Timing> for JsonValue {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
-
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
 
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        for (key, part) in [
+            ("parse", &value.parse),
+            ("part_1", &value.part_1),
+            ("part_2", &value.part_2),
+        ] {
+            map.insert(
+                key.into(),
+                part.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
 
         JsonValue::Object(map)
     }
@@ -151,26 +248,180 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let parse_part = |key: &str| -> Result<Option<PartTiming>, String> {
+            json.get(key)
+                .ok_or(format!("Expected timing.{key} to be null or object."))
+                .and_then(|v| {
+                    if v.is_null() {
+                        Ok(None)
+                    } else {
+                        PartTiming::try_from(v).map(Some)
+                    }
+                })
+        };
+
+        Ok(Timing {
+            day,
+            // NOTE: parse timings are optional, they are only recorded for solutions with a `parse` function.
+            parse: if json.contains_key("parse") {
+                parse_part("parse")?
+            } else {
+                None
+            },
+            part_1: parse_part("part_1")?,
+            part_2: parse_part("part_2")?,
+        })
+    }
+}
+
+impl Timing {
+    /// Read a timing stored in version 1 of the format, which stored parts as Debug-formatted durations.
+    fn try_from_v1(value: &JsonValue) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+        let parse_part = |part: u8| -> Result<Option<PartTiming>, String> {
+            let key = format!("part_{part}");
+
+            let formatted = json
+                .get(&key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected timing.{key} to be null or string."))?;
+
+            // NOTE: statuses and per-part nanos are optional, they were added to version 1 later on.
+            let status = json
+                .get(&format!("{key}_status"))
+                .and_then(|v| v.get::<String>())
+                .map(|x| x.parse::<Status>())
+                .transpose()?;
+
+            let nanos = json
+                .get(&format!("{key}_nanos"))
+                .and_then(|v| v.get::<f64>().copied());
+
+            let nanos = match (nanos, formatted) {
+                (Some(nanos), _) => Some(nanos),
+                (None, Some(formatted)) => Some(parse_duration(formatted).ok_or(format!(
+                    "Expected timing.{key} to be a duration, got `{formatted}`."
+                ))?),
+                (None, None) => None,
+            };
+
+            Ok(match (status, nanos) {
+                (None, None) => None,
+                (status, nanos) => Some(PartTiming {
+                    status: status.unwrap_or(Status::Solved),
+                    nanos: nanos.unwrap_or_default(),
+                    samples: 0,
+                    stats: None,
+                    alloc: None,
+                }),
+            })
+        };
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+            parse: None,
+            part_1: parse_part(1)?,
+            part_2: parse_part(2)?,
+        })
+    }
+}
+
+/// Parse a Debug-formatted [`std::time::Duration`] like `"74.1ms"` into nanoseconds.
+fn parse_duration(s: &str) -> Option<f64> {
+    let units = [("ns", 1.0), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)];
+
+    units.iter().find_map(|(unit, factor)| {
+        let value: f64 = s.strip_suffix(unit)?.trim().parse().ok()?;
+        Some(value * factor)
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartTiming> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "alloc".into(),
+            value
+                .alloc
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected part.status to be a string.")?
+            .parse()?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as usize)
+            .ok_or("Expected part.samples to be a number.")?;
+
+        let stats = json
+            .get("stats")
+            .filter(|v| !v.is_null())
+            .map(Stats::try_from)
+            .transpose()?;
+
+        // NOTE: allocations are optional, they are only counted with the `alloc-stats` feature.
+        let alloc = json
+            .get("alloc")
+            .filter(|v| !v.is_null())
+            .map(AllocStats::try_from)
+            .transpose()?;
+
+        Ok(PartTiming {
+            status,
+            nanos,
+            samples,
+            stats,
+            alloc,
         })
     }
 }
@@ -179,53 +430,79 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, template::protocol::Status};
+
+    use super::{PartTiming, Timing, Timings};
 
-    use super::{Timing, Timings};
+    fn solved(nanos: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            status: Status::Solved,
+            nanos,
+            samples: 100,
+            stats: None,
+            alloc: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    parse: solved(5e+9),
+                    part_1: solved(1e+10),
+                    part_2: solved(2e+10),
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    parse: None,
+                    part_1: solved(3e+10),
+                    part_2: solved(4e+10),
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    parse: None,
+                    part_1: solved(4e+10),
                     part_2: None,
-                    total_nanos: 4e+10,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{protocol::Status, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "status": "solved", "nanos": 1000000, "samples": 10, "stats": null }, "part_2": null }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.as_ref().unwrap();
+            assert_eq!(part_1.status, Status::Solved);
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, 10);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos(), 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_parse_timings() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "parse": { "status": "solved", "nanos": 500000, "samples": 10, "stats": null }, "part_1": { "status": "solved", "nanos": 1000000, "samples": 10, "stats": null }, "part_2": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse.as_ref().unwrap().nanos, 500_000_f64);
+            assert_eq!(timing.part(0), timing.parse.as_ref());
+            assert_eq!(timing.total_nanos(), 1_500_000_f64);
         }
 
         #[test]
         fn handles_empty_timings() {
-            let json = r#"{ "data": [] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 0);
         }
@@ -240,13 +517,59 @@ mod tests {
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
-            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unknown_versions() {
+            let json = r#"{ "version": 99, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+    }
+
+    mod migration {
+        use crate::{
+            day,
+            template::{protocol::Status, timings::Timings},
+        };
+
+        #[test]
+        fn reads_formatted_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.5ms", "part_2": null, "total_nanos": 1500000 }, { "day": "02", "part_1": "74.0ns", "part_2": "2.0s", "total_nanos": 2000000074 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part_1 = timings.data[0].part_1.as_ref().unwrap();
+            assert_eq!(part_1.status, Status::Solved);
+            assert_eq!(part_1.nanos, 1_500_000_f64);
+            assert_eq!(part_1.samples, 0);
+            assert_eq!(timings.data[0].part_2, None);
+            assert_eq!(timings.data[1].day, day!(2));
+            assert_eq!(timings.data[1].total_nanos(), 2_000_000_074_f64);
+        }
+
+        #[test]
+        fn reads_statuses_and_nanos() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "part_1_status": "solved", "part_2_status": "panicked", "part_1_nanos": 1234567, "total_nanos": 1234567 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1.as_ref().unwrap().nanos, 1_234_567_f64);
+            let part_2 = timing.part_2.as_ref().unwrap();
+            assert_eq!(part_2.status, Status::Panicked);
+            assert_eq!(part_2.solved_nanos(), None);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "soon", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
     }
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::Timings;
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -254,11 +577,10 @@ mod tests {
         fn serializes_timings() {
             let timings = get_mock_timings();
             let value = JsonValue::try_from(timings).unwrap();
+            let json = value.get::<HashMap<String, JsonValue>>().unwrap();
+            assert_eq!(json.get("version").unwrap().get::<f64>(), Some(&2.0));
             assert_eq!(
-                value
-                    .get::<HashMap<String, JsonValue>>()
-                    .unwrap()
-                    .get("data")
+                json.get("data")
                     .unwrap()
                     .get::<Vec<JsonValue>>()
                     .unwrap()
@@ -266,26 +588,39 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn round_trips_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data, timings.data);
+        }
     }
 
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                protocol::Status,
+                timings::{PartTiming, Timing, Timings},
+            },
         };
 
+        use super::solved;
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
+                    parse: None,
+                    part_1: solved(1e6),
+                    part_2: solved(2e6),
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +628,47 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    parse: None,
+                    part_1: solved(1e6),
                     part_2: None,
-                    total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_failed_parts() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: solved(1e6),
+                    part_2: Some(PartTiming {
+                        status: Status::Unsolved,
+                        ..solved(0.0).unwrap()
+                    }),
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_not_applicable_parts() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: solved(1e6),
+                    part_2: Some(PartTiming {
+                        status: Status::NotApplicable,
+                        ..solved(0.0).unwrap()
+                    }),
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -307,13 +676,13 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -331,9 +700,9 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0_f64,
                 }],
             };
             let merged = timings.merge(&other);
@@ -351,9 +720,9 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0_f64,
                 }],
             };
             let merged = timings.merge(&other);
@@ -361,7 +730,7 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].total_nanos(), 0_f64);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
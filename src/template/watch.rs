@@ -0,0 +1,256 @@
+//! Change detection and reporting for the `watch` command.
+//!
+//! Files are polled: every tick, the modification time and size of each watched file is compared
+//! against the previous [`Snapshot`]. After a change, the example tests and the solution are re-run
+//! and summarized with [`TestSummary`] and [`diff_answers`].
+use std::{collections::BTreeMap, fs, path::Path, time::SystemTime};
+
+use crate::template::{Puzzle, protocol::PartRecord};
+
+/// Paths watched for a puzzle: the solution, its input and every example file of the day, e.g.
+/// `data/2025/examples/01.txt` and `data/2025/examples/01-2.txt`.
+pub fn watched_paths(puzzle: Puzzle) -> Vec<String> {
+    let mut paths = vec![puzzle.bin_path(), puzzle.data_path("inputs")];
+
+    let examples_dir = format!("{}/examples", puzzle.year.data_dir());
+    let prefix = puzzle.day.to_string();
+
+    if let Ok(entries) = fs::read_dir(&examples_dir) {
+        let mut examples: Vec<String> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+            .map(|name| format!("{examples_dir}/{name}"))
+            .collect();
+        examples.sort();
+        paths.extend(examples);
+    } else {
+        paths.push(puzzle.data_path("examples"));
+    }
+
+    paths
+}
+
+/// Modification time and size of a file, `None` if it does not exist.
+type Stamp = Option<(SystemTime, u64)>;
+
+/// State of the watched files at one point in time.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    files: BTreeMap<String, Stamp>,
+}
+
+impl Snapshot {
+    pub fn take(paths: &[String]) -> Self {
+        let files = paths
+            .iter()
+            .map(|path| {
+                let stamp = fs::metadata(Path::new(path))
+                    .ok()
+                    .and_then(|meta| Some((meta.modified().ok()?, meta.len())));
+                (path.clone(), stamp)
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    /// Paths that were added, removed or modified since an earlier snapshot.
+    pub fn changed_since<'a>(&'a self, earlier: &'a Snapshot) -> Vec<&'a str> {
+        let mut changed: Vec<&str> = self
+            .files
+            .iter()
+            .filter(|(path, stamp)| earlier.files.get(*path) != Some(stamp))
+            .map(|(path, _)| path.as_str())
+            .collect();
+
+        changed.extend(
+            earlier
+                .files
+                .keys()
+                .filter(|path| !self.files.contains_key(*path))
+                .map(String::as_str),
+        );
+
+        changed
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Outcome of `cargo test` for a solution bin.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: usize,
+    /// Names of the failed tests, e.g. `tests::test_part_one`.
+    pub failures: Vec<String>,
+}
+
+impl TestSummary {
+    /// Parse the output of `cargo test`. Returns `None` if no tests were run, e.g. because the
+    /// solution does not compile.
+    pub fn parse(output: &str) -> Option<Self> {
+        let mut summary: Option<Self> = None;
+
+        for line in output.lines() {
+            if let Some(name) = line
+                .strip_prefix("test ")
+                .and_then(|x| x.strip_suffix(" ... FAILED"))
+            {
+                summary.get_or_insert_default().failures.push(name.into());
+            } else if let Some(counts) = line.strip_prefix("test result: ") {
+                let summary = summary.get_or_insert_default();
+                summary.passed += count(counts, "passed");
+                summary.failed += count(counts, "failed");
+            }
+        }
+
+        summary.filter(|x| x.passed + x.failed > 0 || !x.failures.is_empty())
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+/// Number in front of a label in a line like `ok. 3 passed; 0 failed; 0 ignored`.
+fn count(counts: &str, label: &str) -> usize {
+    counts
+        .split(';')
+        .filter_map(|x| x.trim().rsplit_once(' '))
+        .find(|(_, name)| *name == label)
+        .and_then(|(value, _)| value.rsplit(' ').next()?.parse().ok())
+        .unwrap_or(0)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Answer of a part compared to the previous run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnswerChange {
+    pub part: u8,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl AnswerChange {
+    pub fn is_changed(&self) -> bool {
+        self.before != self.after
+    }
+}
+
+/// Compare the answers of both parts against the records of the previous run.
+pub fn diff_answers(previous: &[PartRecord], current: &[PartRecord]) -> Vec<AnswerChange> {
+    let answer = |records: &[PartRecord], part: u8| {
+        records
+            .iter()
+            .find(|record| record.part == part)
+            .and_then(|record| record.answer.clone())
+    };
+
+    [1, 2]
+        .into_iter()
+        .filter(|&part| current.iter().chain(previous).any(|r| r.part == part))
+        .map(|part| AnswerChange {
+            part,
+            before: answer(previous, part),
+            after: answer(current, part),
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::{AnswerChange, Snapshot, TestSummary, diff_answers};
+    use crate::{
+        day,
+        template::protocol::{PartRecord, Status},
+    };
+
+    #[test]
+    fn parses_test_results() {
+        let output = "\
+running 3 tests
+test tests::test_part_one ... ok
+test tests::test_part_two ... FAILED
+test tests::test_parse ... ok
+
+test result: FAILED. 2 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+        assert_eq!(
+            TestSummary::parse(output),
+            Some(TestSummary {
+                passed: 2,
+                failed: 1,
+                failures: vec!["tests::test_part_two".into()],
+            })
+        );
+    }
+
+    #[test]
+    fn detects_missing_test_results() {
+        assert_eq!(TestSummary::parse("error[E0308]: mismatched types"), None);
+        assert!(
+            TestSummary::parse("test result: ok. 2 passed; 0 failed; 0 ignored")
+                .unwrap()
+                .is_success()
+        );
+    }
+
+    #[test]
+    fn detects_changed_files() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let snapshot = |files: &[(&str, Option<u64>)]| Snapshot {
+            files: files
+                .iter()
+                .map(|(path, len)| (path.to_string(), len.map(|len| (time, len))))
+                .collect(),
+        };
+
+        let before = snapshot(&[("a", Some(1)), ("b", None), ("c", Some(3))]);
+        let after = snapshot(&[("a", Some(1)), ("b", Some(2)), ("d", Some(4))]);
+
+        assert_eq!(after.changed_since(&before), vec!["b", "d", "c"]);
+        assert!(after.changed_since(&after).is_empty());
+    }
+
+    #[test]
+    fn diffs_answers_of_both_parts() {
+        let record = |part, answer: &str| PartRecord {
+            day: day!(1),
+            part,
+            status: Status::Solved,
+            answer: Some(answer.into()),
+            message: None,
+            nanos: 100.0,
+            samples: 1,
+            stats: None,
+            alloc: None,
+        };
+
+        let changes = diff_answers(&[record(1, "42")], &[record(1, "42"), record(2, "7")]);
+
+        assert_eq!(
+            changes,
+            vec![
+                AnswerChange {
+                    part: 1,
+                    before: Some("42".into()),
+                    after: Some("42".into()),
+                },
+                AnswerChange {
+                    part: 2,
+                    before: None,
+                    after: Some("7".into()),
+                },
+            ]
+        );
+        assert!(!changes[0].is_changed());
+        assert!(changes[1].is_changed());
+    }
+}
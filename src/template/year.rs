@@ -0,0 +1,145 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{AllDays, Day};
+
+/// Year of the first Advent of Code event.
+const FIRST_YEAR: u16 = 2015;
+
+/// Year from which on events only have 12 days.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// A valid year of an Advent of Code event (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's a year with an event,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Reads the default year from the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Number of days of the event. Events before 2025 have 25 days, later ones 12.
+    pub const fn max_day(self) -> u8 {
+        if self.0 < FIRST_SHORT_YEAR { 25 } else { 12 }
+    }
+
+    /// An iterator that yields every day of the event.
+    pub fn days(self) -> AllDays {
+        AllDays::new(self)
+    }
+
+    /// Directory that holds the data of this year, e.g. `data/2025`.
+    pub fn data_dir(self) -> String {
+        format!("data/{self}")
+    }
+
+    /// Returns the current year.
+    #[cfg(feature = "today")]
+    pub fn current() -> Self {
+        use chrono::Datelike;
+        Self(u16::try_from(chrono::Utc::now().year()).unwrap_or(FIRST_SHORT_YEAR))
+    }
+
+    /// Whether a day is part of the event.
+    pub fn has_day(self, day: Day) -> bool {
+        day.into_inner() <= self.max_day()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of an event, i.e. 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting the year of an event (2015 or later)")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::day;
+
+    #[test]
+    fn validates_years() {
+        assert_eq!(Year::new(2014), None);
+        assert!(Year::new(2015).is_some());
+        assert!("2030".parse::<Year>().is_ok());
+        assert!("12".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn limits_days_per_year() {
+        assert_eq!(Year::new(2024).unwrap().max_day(), 25);
+        assert_eq!(Year::new(2025).unwrap().max_day(), 12);
+        assert!(Year::new(2024).unwrap().has_day(day!(25)));
+        assert!(!Year::new(2025).unwrap().has_day(day!(13)));
+    }
+
+    #[test]
+    fn iterates_days_of_year() {
+        assert_eq!(Year::new(2023).unwrap().days().count(), 25);
+        assert_eq!(Year::new(2025).unwrap().days().last(), Some(day!(12)));
+    }
+}
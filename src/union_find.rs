@@ -0,0 +1,227 @@
+//! Disjoint sets of nodes `0..n`, e.g. circuits of connected junction boxes.
+//!
+//! [`UnionFind`] merges sets by size and compresses paths while looking up roots, so any sequence of
+//! operations takes nearly constant time per operation. [`kruskal`] connects nodes along a sorted list
+//! of edges and reports every connection it makes, which covers both "connect the closest n pairs" and
+//! "connect until everything is one component".
+//!
+//! ```
+//! use advent_of_code::union_find::kruskal;
+//!
+//! let mut connections = kruskal(4, [(0, 1), (2, 3), (1, 0), (1, 2)]);
+//! let last = connections.find(|connection| connection.components == 1).unwrap();
+//! assert_eq!(last.edge, (1, 2));
+//! ```
+
+/// A partition of the nodes `0..n` into disjoint components.
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    /// Parent of each node, roots are their own parent.
+    parent: Vec<usize>,
+    /// Number of nodes in the component of each root.
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Creates `n` components of a single node each.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint components.
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Root of the component of a node. Points the nodes on the way directly at the root.
+    pub fn find(&mut self, node: usize) -> usize {
+        let mut root = node;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut node = node;
+        while self.parent[node] != root {
+            node = std::mem::replace(&mut self.parent[node], root);
+        }
+
+        root
+    }
+
+    /// Merges the components of two nodes, attaching the smaller one to the larger one. Returns the
+    /// root of the merged component, or `None` if both nodes were already in the same component.
+    pub fn union(&mut self, a: usize, b: usize) -> Option<usize> {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return None;
+        }
+
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+
+        Some(a)
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of nodes in the component of a node.
+    pub fn component_size(&mut self, node: usize) -> usize {
+        let root = self.find(node);
+        self.size[root]
+    }
+
+    /// Sizes of all components, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|&node| self.parent[node] == node)
+            .map(|root| self.size[root])
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An edge that was processed by [`kruskal`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Connection {
+    pub edge: (usize, usize),
+    /// Whether the edge joined two components, `false` if its nodes were connected already.
+    pub merged: bool,
+    /// Size of the component that contains the edge afterwards.
+    pub size: usize,
+    /// Number of components afterwards.
+    pub components: usize,
+}
+
+/// Iterator that connects nodes along edges, see [`kruskal`].
+#[derive(Clone, Debug)]
+pub struct Kruskal<I> {
+    edges: I,
+    union_find: UnionFind,
+}
+
+/// Connects the nodes `0..n` along edges in the given order, which is usually by ascending weight.
+/// Yields a [`Connection`] per edge; the ones that merged components form a minimum spanning forest.
+pub fn kruskal<I>(n: usize, edges: I) -> Kruskal<I::IntoIter>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    Kruskal {
+        edges: edges.into_iter(),
+        union_find: UnionFind::new(n),
+    }
+}
+
+impl<I> Kruskal<I> {
+    /// The components connected so far.
+    pub fn union_find(&self) -> &UnionFind {
+        &self.union_find
+    }
+
+    pub fn into_union_find(self) -> UnionFind {
+        self.union_find
+    }
+}
+
+impl<I: Iterator<Item = (usize, usize)>> Iterator for Kruskal<I> {
+    type Item = Connection;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (a, b) = self.edges.next()?;
+        let merged = self.union_find.union(a, b).is_some();
+
+        Some(Connection {
+            edge: (a, b),
+            merged,
+            size: self.union_find.component_size(a),
+            components: self.union_find.component_count(),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{UnionFind, kruskal};
+
+    #[test]
+    fn merges_components() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.component_count(), 6);
+
+        assert!(sets.union(0, 1).is_some());
+        assert!(sets.union(2, 3).is_some());
+        assert!(sets.union(1, 3).is_some());
+        assert_eq!(sets.union(0, 2), None);
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.component_size(2), 4);
+        assert_eq!(sets.component_count(), 3);
+        assert_eq!(sets.component_sizes(), vec![4, 1, 1]);
+    }
+
+    #[test]
+    fn attaches_smaller_components_to_larger_ones() {
+        let mut sets = UnionFind::new(5);
+        sets.union(1, 2);
+        sets.union(1, 3);
+        let root = sets.find(1);
+
+        assert_eq!(sets.union(4, 2), Some(root));
+        assert_eq!(sets.union(0, 4), Some(root));
+        assert_eq!(sets.component_sizes(), vec![5]);
+    }
+
+    #[test]
+    fn compresses_paths() {
+        // a chain 4 -> 3 -> 2 -> 1 -> 0, which union by size alone never builds.
+        let mut sets = UnionFind::new(5);
+        sets.parent = vec![0, 0, 1, 2, 3];
+
+        assert_eq!(sets.find(4), 0);
+        assert_eq!(sets.parent, vec![0; 5]);
+    }
+
+    #[test]
+    fn reports_connections() {
+        let edges = [(0, 1), (1, 2), (0, 2), (3, 4), (2, 3)];
+        let connections: Vec<_> = kruskal(5, edges).collect();
+
+        let merged: Vec<_> = connections.iter().map(|c| c.merged).collect();
+        assert_eq!(merged, vec![true, true, false, true, true]);
+        assert_eq!(connections[2].size, 3);
+        assert_eq!(connections[4].components, 1);
+
+        let mut partial = kruskal(5, edges);
+        partial.by_ref().take(3).for_each(drop);
+        assert_eq!(partial.union_find().component_sizes(), vec![3, 1, 1]);
+    }
+}